@@ -19,11 +19,11 @@ mkdir -p data/{Hipparcos,Yale,OSBSC}
 
 (
     cd data/Yale
+    # NOTE: no need to gunzip, the catalog parsers decompress transparently.
     wget -cv 'http://tdc-www.harvard.edu/catalogs/bsc5.dat.gz'
     wget -cv 'http://tdc-www.harvard.edu/catalogs/bsc5.readme'
     wget -cv 'http://tdc-www.harvard.edu/catalogs/bsc5.notes.gz'
-    gunzip bsc5.dat.gz
-    gunzip bsc5.notes.gz
+    wget -cv 'http://tdc-www.harvard.edu/catalogs/BSC5'
 )
 
 (
@@ -4,6 +4,8 @@ Basic angle structures (Radian, Degree, Arc Hour)
 
 use auto_ops::*;
 
+use std::fmt;
+
 pub use std::f64::consts::PI;
 
 /// 2π
@@ -13,78 +15,154 @@ pub const PI_HALF: f64 = PI / 2.0;
 /// π/4
 pub const PI_FOURTH: f64 = PI / 4.0;
 
-/// Enum containing common angle variants
-#[derive(Copy, Clone, Debug, PartialEq)]
-pub enum Angle {
-    /// Variant containing decimal degree
-    Degree(f64),
-    /// Variant containing decimal radian
-    Radian(f64),
-    /// Variant containing decimal hour
-    Hour(f64),
+/**
+Angle stored as a decimal radian
+
+Degree and hour values are converted once on construction, so trigonometry and arithmetic need no unit dispatch.
+ */
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd)]
+pub struct Angle(f64);
+
+/// Compatibility constructors named after the former `Angle` enum variants
+#[allow(non_snake_case)]
+impl Angle {
+    /// Angle from a decimal degree
+    #[inline]
+    pub fn Degree(deg: f64) -> Self {
+        Self::from_deg(deg)
+    }
+    /// Angle from a decimal radian
+    #[inline]
+    pub fn Radian(rad: f64) -> Self {
+        Self::from_rad(rad)
+    }
+    /// Angle from a decimal hour
+    #[inline]
+    pub fn Hour(hr: f64) -> Self {
+        Self::from_hr(hr)
+    }
 }
 
 impl Angle {
+    /// Angle from a decimal degree
+    #[inline]
+    pub fn from_deg(deg: f64) -> Self {
+        Self(deg.to_radians())
+    }
+    /// Angle from a decimal radian
+    #[inline]
+    pub const fn from_rad(rad: f64) -> Self {
+        Self(rad)
+    }
+    /// Angle from a decimal hour
+    #[inline]
+    pub fn from_hr(hr: f64) -> Self {
+        Self((15.0 * hr).to_radians())
+    }
     /// Convert to decimal degree
+    #[inline]
     pub fn to_deg(&self) -> f64 {
-        match self {
-            Self::Degree(deg) => *deg,
-            Self::Hour(hr) => 15.0 * hr,
-            Self::Radian(rad) => {
-                const RAD_TO_DEG: f64 = 180.0 / PI;
-                RAD_TO_DEG * rad
-            }
-        }
+        self.0.to_degrees()
     }
     /// Convert to decimal radian
+    #[inline]
     pub fn to_rad(&self) -> f64 {
-        match self {
-            Self::Degree(deg) => {
-                const DEG_TO_RAD: f64 = PI / 180.0;
-                DEG_TO_RAD * deg
-            }
-            Self::Hour(hr) => {
-                const HOUR_TO_RAD: f64 = 15.0 * PI / 180.0;
-                HOUR_TO_RAD * hr
-            }
-            Self::Radian(rad) => *rad,
-        }
+        self.0
     }
     /// Convert to decimal hour
+    #[inline]
     pub fn to_hr(&self) -> f64 {
-        match self {
-            Self::Degree(deg) => deg / 15.0,
-            Self::Hour(hr) => *hr,
-            Self::Radian(rad) => {
-                const RAD_TO_HOUR: f64 = 180.0 / PI / 15.0;
-                RAD_TO_HOUR * rad
-            }
-        }
+        self.0.to_degrees() / 15.0
     }
+    #[inline]
     pub fn sin(&self) -> f64 {
-        Self::to_rad(self).sin()
+        self.0.sin()
     }
+    #[inline]
     pub fn cos(&self) -> f64 {
-        Self::to_rad(self).cos()
+        self.0.cos()
     }
+    #[inline]
     pub fn tan(&self) -> f64 {
-        Self::to_rad(self).tan()
+        self.0.tan()
+    }
+    /// Sine and cosine at once
+    #[inline]
+    pub fn sin_cos(&self) -> (f64, f64) {
+        self.0.sin_cos()
+    }
+    /// Arcsine
+    #[inline]
+    pub fn asin(x: f64) -> Self {
+        Self(x.asin())
+    }
+    /// Arccosine
+    #[inline]
+    pub fn acos(x: f64) -> Self {
+        Self(x.acos())
+    }
+    /// Arctangent
+    #[inline]
+    pub fn atan(x: f64) -> Self {
+        Self(x.atan())
+    }
+    /// Four quadrant arctangent of `y / x`
+    #[inline]
+    pub fn atan2(y: f64, x: f64) -> Self {
+        Self(y.atan2(x))
+    }
+    /// Angle from decimal arc minutes
+    pub fn from_arcmin(arcmin: f64) -> Self {
+        Self::from_deg(arcmin / 60.0)
+    }
+    /// Angle from decimal arc seconds
+    pub fn from_arcsec(arcsec: f64) -> Self {
+        Self::from_deg(arcsec / 3600.0)
+    }
+    /// Angle from decimal milliarcseconds
+    pub fn from_mas(mas: f64) -> Self {
+        Self::from_deg(mas / 3_600_000.0)
+    }
+    /// Convert to decimal arc minutes
+    pub fn to_arcmin(&self) -> f64 {
+        self.to_deg() * 60.0
+    }
+    /// Convert to decimal arc seconds
+    pub fn to_arcsec(&self) -> f64 {
+        self.to_deg() * 3600.0
+    }
+    /// Convert to decimal milliarcseconds
+    pub fn to_mas(&self) -> f64 {
+        self.to_deg() * 3_600_000.0
     }
-}
 
-impl_op_ex!(+ |a: &Angle, b: &Angle| -> Angle { match a {
-    Angle::Degree(deg) => Angle::Degree(deg + b.to_deg()),
-    Angle::Radian(rad) => Angle::Degree(rad + b.to_rad()),
-    Angle::Hour(hr) => Angle::Degree(hr + b.to_hr()),
-} });
+    /// Wrap between 0 and 360 degrees
+    pub fn normalize_0_360(&self) -> Self {
+        Self(self.0.rem_euclid(TWO_PI))
+    }
+    /// Wrap between -180 (inclusive) and 180 (exclusive) degrees
+    pub fn normalize_pm180(&self) -> Self {
+        Self((self.0 + PI).rem_euclid(TWO_PI) - PI)
+    }
+    /// Wrap between 0 and 24 hours
+    pub fn normalize_0_24h(&self) -> Self {
+        self.normalize_0_360()
+    }
+    /**
+    Shortest signed angle from `other` to `self`, between -180 (inclusive) and 180 (exclusive) degrees.
 
-impl_op_ex!(-|a: &Angle, b: &Angle| -> Angle {
-    match a {
-        Angle::Degree(deg) => Angle::Degree(deg - b.to_deg()),
-        Angle::Radian(rad) => Angle::Degree(rad - b.to_rad()),
-        Angle::Hour(hr) => Angle::Degree(hr - b.to_hr()),
+    E.g. `Angle::Degree(10.0).difference(&Angle::Degree(350.0))` is 20 degrees.
+    */
+    pub fn difference(&self, other: &Angle) -> Self {
+        (self - other).normalize_pm180()
     }
-});
+}
+
+impl_op_ex!(+|a: &Angle, b: &Angle| -> Angle { Angle(a.0 + b.0) });
+impl_op_ex!(-|a: &Angle, b: &Angle| -> Angle { Angle(a.0 - b.0) });
+impl_op_ex!(-|a: &Angle| -> Angle { Angle(-a.0) });
+impl_op_ex_commutative!(*|a: &Angle, b: &f64| -> Angle { Angle(a.0 * b) });
+impl_op_ex!(/|a: &Angle, b: &f64| -> Angle { Angle(a.0 / b) });
 
 /// Enum representing sign of number
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -126,32 +204,212 @@ Angle to sexagesimal angle implementation
 
 */
 macro_rules! impl_arc_minute_second {
-    ($T:ty) => {
+    ($T:ident, [$major:literal, $minor:literal, $second:literal], $default_precision:literal, $units:literal) => {
         impl $T {
             /**
             Decimal angle to Arc Minute Second struct.
             */
             pub fn angle_to_ams(decimal_angle: f64) -> Self {
-                let major: u32 = decimal_angle as u32;
-                let min: f64 = (decimal_angle - (major as f64)) * 60.0;
-                let second: f64 = (min - (min as u32 as f64)) * 60.0;
+                let abs: f64 = decimal_angle.abs();
+                let mut major: u32 = abs as u32;
+                let mut min: f64 = (abs - (major as f64)) * 60.0;
+                let mut second: f64 = (min - (min as u32 as f64)) * 60.0;
+                // NOTE: floating point error can leave a full minute (or hour/degree) in the lower part.
+                if second >= 60.0 {
+                    second -= 60.0;
+                    min += 1.0;
+                }
+                if min >= 60.0 {
+                    min -= 60.0;
+                    major += 1;
+                }
+                Self(sign_of(decimal_angle), major, min as u32, second)
+            }
+
+            /**
+            Decimal angle to Arc Minute Second struct with the seconds rounded to `precision` decimals.
+
+            Rounding carries into the minutes and the major part, e.g. 29.99999999 becomes 30 00 00.00 rather than 29 59 60.00.
+            */
+            pub fn angle_to_ams_rounded(decimal_angle: f64, precision: usize) -> Self {
+                // NOTE: keep the scaled total within the exact integer range of an f64.
+                let scale: f64 = 10_f64.powi(precision.min(9) as i32);
+                let total: u64 = (decimal_angle.abs() * 3600.0 * scale).round() as u64;
+                let per_minute: u64 = (60.0 * scale) as u64;
                 Self(
-                    if decimal_angle.is_sign_positive() {
-                        Sign::Positive
-                    } else {
-                        Sign::Negative
-                    },
-                    major,
-                    min as u32,
-                    second,
+                    sign_of(decimal_angle),
+                    (total / (60 * per_minute)) as u32,
+                    (total / per_minute % 60) as u32,
+                    (total % per_minute) as f64 / scale,
                 )
             }
+
+            /// Decimal value of the sexagesimal angle
+            pub fn to_decimal(&self) -> f64 {
+                let abs: f64 = self.1 as f64 + self.2 as f64 / 60.0 + self.3 / 3600.0;
+                match self.0 {
+                    Sign::Positive => abs,
+                    Sign::Negative => -abs,
+                }
+            }
+
+            /// Round the seconds to `precision` decimals, carrying into the minutes and the major part.
+            pub fn round(&self, precision: usize) -> Self {
+                let rounded = Self::angle_to_ams_rounded(self.to_decimal(), precision);
+                // NOTE: keep the sign of negative zero.
+                Self(self.0.clone(), rounded.1, rounded.2, rounded.3)
+            }
+        }
+
+        /**
+        Formats as e.g. `12h 34m 56.78s` or `-12° 34′ 56.7″`, or with colons (`12:34:56.78`) using the alternate flag `{:#}`.
+
+        The seconds are rounded to the formatter precision (2 decimals for hours and 1 for degrees by default).
+        */
+        impl fmt::Display for $T {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let precision = f.precision().unwrap_or($default_precision);
+                let rounded = self.round(precision);
+                let width = if precision > 0 { precision + 3 } else { 2 };
+                let sign = match rounded.0 {
+                    Sign::Positive => "",
+                    Sign::Negative => "-",
+                };
+                if f.alternate() {
+                    write!(
+                        f,
+                        "{}{:02}:{:02}:{:0width$.precision$}",
+                        sign,
+                        rounded.1,
+                        rounded.2,
+                        rounded.3,
+                        width = width,
+                        precision = precision
+                    )
+                } else {
+                    write!(
+                        f,
+                        concat!(
+                            "{}{}",
+                            $major,
+                            " {:02}",
+                            $minor,
+                            " {:0width$.precision$}",
+                            $second
+                        ),
+                        sign,
+                        rounded.1,
+                        rounded.2,
+                        rounded.3,
+                        width = width,
+                        precision = precision
+                    )
+                }
+            }
+        }
+
+        /**
+        Parses the common sexagesimal notations, e.g. `12:34:56.78`, `12 34 56.78`, `12_34_56.78` (OSBSC), `12h 34m 56.78s` or `-12° 34′ 56.7″`.
+
+        Trailing parts may be omitted, e.g. `12:34.5` or `12.5`.
+        */
+        impl std::str::FromStr for $T {
+            type Err = ParseSexagesimalError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let invalid = |reason: &'static str| ParseSexagesimalError {
+                    text: s.to_string(),
+                    reason,
+                };
+
+                let trimmed = s.trim();
+                // NOTE: also accept the unicode minus sign used in typeset tables.
+                let (sign, unsigned) = match trimmed.strip_prefix(['-', '\u{2212}']) {
+                    Some(unsigned) => (Sign::Negative, unsigned),
+                    None => (Sign::Positive, trimmed.strip_prefix('+').unwrap_or(trimmed)),
+                };
+
+                if let Some(c) = unsigned.chars().find(|c| {
+                    !(c.is_ascii_digit()
+                        || *c == '.'
+                        || c.is_whitespace()
+                        || ":_".contains(*c)
+                        || $units.contains(*c))
+                }) {
+                    return Err(invalid(if c == '-' || c == '+' {
+                        "sign must lead"
+                    } else {
+                        "unexpected character"
+                    }));
+                }
+
+                let parts: Vec<&str> = unsigned
+                    .split(|c: char| !(c.is_ascii_digit() || c == '.'))
+                    .filter(|part| !part.is_empty())
+                    .collect();
+                if parts.is_empty() || parts.len() > 3 {
+                    return Err(invalid("expected one to three sexagesimal parts"));
+                }
+                if parts[..parts.len() - 1]
+                    .iter()
+                    .any(|part| part.contains('.'))
+                {
+                    return Err(invalid("only the last part may have decimals"));
+                }
+                let values = parts
+                    .iter()
+                    .map(|part| part.parse::<f64>())
+                    .collect::<Result<Vec<f64>, _>>()
+                    .map_err(|_| invalid("invalid number"))?;
+                if values[1..].iter().any(|value| *value >= 60.0) {
+                    return Err(invalid("minutes and seconds must be less than 60"));
+                }
+
+                Ok(match values[..] {
+                    [major, minor, second] => Self(sign, major as u32, minor as u32, second),
+                    _ => {
+                        let abs = values[0] + values.get(1).map_or(0.0, |minor| minor / 60.0);
+                        let ams = Self::angle_to_ams(abs);
+                        Self(sign, ams.1, ams.2, ams.3)
+                    }
+                })
+            }
         }
     };
 }
 
-impl_arc_minute_second!(DegMinSec);
-impl_arc_minute_second!(HourMinSec);
+/// Sign of a decimal angle, negative zero is negative.
+fn sign_of(decimal_angle: f64) -> Sign {
+    if decimal_angle.is_sign_positive() {
+        Sign::Positive
+    } else {
+        Sign::Negative
+    }
+}
+
+/// Error returned when parsing a [`DegMinSec`] or [`HourMinSec`] fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseSexagesimalError {
+    /// Text that was parsed
+    pub text: String,
+    /// Why the text was rejected
+    pub reason: &'static str,
+}
+
+impl fmt::Display for ParseSexagesimalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid sexagesimal angle \"{}\": {}",
+            self.text, self.reason
+        )
+    }
+}
+
+impl std::error::Error for ParseSexagesimalError {}
+
+impl_arc_minute_second!(DegMinSec, ["°", "′", "″"], 1, "°'\"′″dms");
+impl_arc_minute_second!(HourMinSec, ["h", "m", "s"], 2, "hms");
 
 impl From<Angle> for DegMinSec {
     fn from(angle: Angle) -> Self {
@@ -250,6 +508,183 @@ mod tests {
         assert_float_absolute_eq!(1.0, Angle::Radian(PI_FOURTH).tan());
     }
 
+    #[test]
+    fn angle_inverse_trig() {
+        assert_float_absolute_eq!(Angle::asin(0.5).to_deg(), 30.0);
+        assert_float_absolute_eq!(Angle::acos(0.5).to_deg(), 60.0);
+        assert_float_absolute_eq!(Angle::atan(1.0).to_rad(), PI_FOURTH);
+        assert_float_absolute_eq!(Angle::atan2(-1.0, -1.0).to_deg(), -135.0);
+        assert_float_absolute_eq!(Angle::atan2(1.0, 0.0).to_hr(), 6.0);
+
+        let (sin, cos) = Angle::Hour(4.0).sin_cos();
+        assert_float_absolute_eq!(sin, 3_f64.sqrt() / 2.0);
+        assert_float_absolute_eq!(cos, 0.5);
+    }
+
+    #[test]
+    fn angle_units() {
+        assert_float_absolute_eq!(Angle::from_arcmin(30.0).to_deg(), 0.5);
+        assert_float_absolute_eq!(Angle::from_arcsec(1296000.0).to_rad(), TWO_PI);
+        assert_float_absolute_eq!(Angle::from_mas(3_600_000.0).to_deg(), 1.0);
+        assert_float_absolute_eq!(Angle::Degree(1.5).to_arcmin(), 90.0);
+        assert_float_absolute_eq!(Angle::Hour(1.0).to_arcsec(), 54000.0);
+        assert_float_absolute_eq!(Angle::from_mas(-12.5).to_mas(), -12.5, 1e-9);
+        assert_float_absolute_eq!(Angle::Radian(PI).to_arcsec(), 648000.0, 1e-9);
+    }
+
+    #[test]
+    fn angle_constructors() {
+        assert_eq!(Angle::Degree(90.0), Angle::from_deg(90.0));
+        assert_eq!(Angle::Radian(PI_HALF), Angle::from_rad(PI_HALF));
+        assert_eq!(Angle::Hour(6.0), Angle::from_hr(6.0));
+        assert_eq!(Angle::Degree(180.0), Angle::Radian(PI));
+        assert_eq!(Angle::default().to_rad(), 0.0);
+    }
+
+    #[test]
+    fn angle_ops() {
+        // arithmetic mixing units
+        assert_eq!(Angle::Radian(PI) + Angle::Radian(PI), Angle::Radian(TWO_PI));
+        assert_eq!(Angle::Hour(6.0) + Angle::Degree(90.0), Angle::Hour(12.0));
+        assert_eq!(Angle::Degree(90.0) - Angle::Hour(3.0), Angle::Degree(45.0));
+        assert_eq!(Angle::Radian(PI) - Angle::Degree(180.0), Angle::Radian(0.0));
+        assert_eq!(-Angle::Hour(2.0), Angle::Hour(-2.0));
+        assert_eq!(Angle::Degree(30.0) * 2.0, Angle::Degree(60.0));
+        assert_eq!(2.0 * Angle::Hour(3.0), Angle::Hour(6.0));
+        assert_eq!(Angle::Radian(PI) / 2.0, Angle::Radian(PI_HALF));
+
+        // comparisons mixing units
+        assert!(Angle::Degree(1.0) < Angle::Radian(1.0));
+        assert!(Angle::Hour(1.0) > Angle::Degree(14.0));
+        assert_eq!(Angle::Degree(180.0), Angle::Radian(PI));
+
+        // wrapping
+        assert_float_absolute_eq!(Angle::Degree(-30.0).normalize_0_360().to_deg(), 330.0);
+        assert_float_absolute_eq!(Angle::Radian(5.0 * PI).normalize_0_360().to_deg(), 180.0);
+        assert_float_absolute_eq!(Angle::Degree(190.0).normalize_pm180().to_deg(), -170.0);
+        assert_float_absolute_eq!(Angle::Degree(180.0).normalize_pm180().to_deg(), -180.0);
+        assert_float_absolute_eq!(Angle::Degree(-45.0).normalize_pm180().to_deg(), -45.0);
+        assert_float_absolute_eq!(Angle::Hour(-1.5).normalize_0_24h().to_hr(), 22.5);
+        assert_float_absolute_eq!(Angle::Degree(370.0).normalize_0_24h().to_hr(), 2.0 / 3.0);
+
+        // differences across the wrap
+        assert_float_absolute_eq!(
+            Angle::Degree(10.0)
+                .difference(&Angle::Degree(350.0))
+                .to_deg(),
+            20.0
+        );
+        assert_float_absolute_eq!(
+            Angle::Degree(350.0)
+                .difference(&Angle::Degree(10.0))
+                .to_deg(),
+            -20.0
+        );
+        assert_float_absolute_eq!(Angle::Hour(0.5).difference(&Angle::Hour(23.5)).to_hr(), 1.0);
+        assert_float_absolute_eq!(
+            Angle::Radian(0.1).difference(&Angle::Degree(0.0)).to_rad(),
+            0.1
+        );
+    }
+
+    #[test]
+    fn arc_min_sec_rounding() {
+        // Negative angles keep their magnitude
+        assert_eq!(
+            DegMinSec::angle_to_ams(-12.5),
+            DegMinSec(Sign::Negative, 12, 30, 0.0)
+        );
+
+        // Rounding carries into minutes and degrees
+        assert_eq!(
+            DegMinSec::angle_to_ams_rounded(29.99999999, 2),
+            DegMinSec(Sign::Positive, 30, 0, 0.0)
+        );
+        assert_eq!(
+            HourMinSec::angle_to_ams_rounded(-1.0 - 59.0 / 60.0 - 59.996 / 3600.0, 2),
+            HourMinSec(Sign::Negative, 2, 0, 0.0)
+        );
+        assert_eq!(
+            HourMinSec(Sign::Positive, 12, 34, 56.784).round(2),
+            HourMinSec(Sign::Positive, 12, 34, 56.78)
+        );
+        assert_eq!(
+            DegMinSec(Sign::Negative, 0, 0, 0.0).round(1),
+            DegMinSec(Sign::Negative, 0, 0, 0.0)
+        );
+    }
+
+    #[test]
+    fn arc_min_sec_display() {
+        let hms = HourMinSec(Sign::Positive, 12, 34, 56.78);
+        assert_eq!(hms.to_string(), "12h 34m 56.78s");
+        assert_eq!(format!("{:#}", hms), "12:34:56.78");
+        assert_eq!(format!("{:.0}", hms), "12h 34m 57s");
+
+        let dms = DegMinSec(Sign::Negative, 12, 34, 56.74);
+        assert_eq!(dms.to_string(), "-12° 34′ 56.7″");
+        assert_eq!(format!("{:#.3}", dms), "-12:34:56.740");
+        assert_eq!(
+            DegMinSec::angle_to_ams(29.99999999).to_string(),
+            "30° 00′ 00.0″"
+        );
+        assert_eq!(
+            DegMinSec(Sign::Positive, 1, 5, 3.0).to_string(),
+            "1° 05′ 03.0″"
+        );
+    }
+
+    #[test]
+    fn arc_min_sec_parse() {
+        let hms = HourMinSec(Sign::Positive, 12, 34, 56.78);
+        for text in [
+            "12h 34m 56.78s",
+            "12h34m56.78s",
+            "12:34:56.78",
+            "12 34 56.78",
+            "12_34_56.78",
+            " +12:34:56.78 ",
+        ] {
+            assert_eq!(text.parse::<HourMinSec>(), Ok(hms.clone()), "{}", text);
+        }
+
+        let dms = DegMinSec(Sign::Negative, 48, 48, 35.49);
+        for text in [
+            "-48° 48′ 35.49″",
+            "-48°48'35.49\"",
+            "-48:48:35.49",
+            "-48 48 35.49",
+            "-48_48_35.49",
+            "−48d48m35.49s",
+        ] {
+            assert_eq!(text.parse::<DegMinSec>(), Ok(dms.clone()), "{}", text);
+        }
+
+        // Shorter forms
+        assert_eq!(
+            "-0:30".parse::<DegMinSec>(),
+            Ok(DegMinSec(Sign::Negative, 0, 30, 0.0))
+        );
+        assert_eq!(
+            "6.5h".parse::<HourMinSec>(),
+            Ok(HourMinSec(Sign::Positive, 6, 30, 0.0))
+        );
+
+        // Round trip
+        assert_eq!(
+            dms.to_string().parse::<DegMinSec>().unwrap().to_string(),
+            dms.to_string()
+        );
+
+        // Errors
+        assert!("".parse::<HourMinSec>().is_err());
+        assert!("12:60:00".parse::<HourMinSec>().is_err());
+        assert!("12.5:30:00".parse::<HourMinSec>().is_err());
+        assert!("12:30:00:00".parse::<DegMinSec>().is_err());
+        assert!("12° 30′".parse::<HourMinSec>().is_err());
+        assert!("12:-30:00".parse::<DegMinSec>().is_err());
+    }
+
     #[test]
     fn arc_min_sec() {
         // DegMinSec
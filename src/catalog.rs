@@ -1,11 +1,32 @@
 //! Star catalog parsers
 
+pub mod bsc5;
 pub mod hipparcos;
 pub mod osbsc;
 pub mod util;
 pub mod yale;
 
-/// Determines if a parsed record is valid. User can implement this to require certain fields or conditions.
-pub trait ValidParse {
-    fn is_valid_parse(&self) -> bool;
+pub use crate::parse::reader;
+pub use crate::parse::{CatalogReader, CatalogRecord, ParseError, ParseErrorKind, ValidParse};
+pub use util::parse_catalog;
+
+/// Records parsed from a catalog together with every line that was rejected.
+#[derive(Debug)]
+pub struct Catalog<T> {
+    /// Successfully parsed records
+    pub records: Vec<T>,
+    /// Errors for the lines that did not produce a record
+    pub rejected: Vec<ParseError>,
+}
+
+impl<T> Catalog<T> {
+    /// Number of parsed records
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Whether no records were parsed
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
 }
@@ -0,0 +1,483 @@
+/*!
+[Yale Bright Star Catalog](http://tdc-www.harvard.edu/catalogs/bsc5.html) binary (`BSC5`) parser
+
+The Harvard distribution ships the catalog as a compact binary file alongside `bsc5.dat`.
+See <http://tdc-www.harvard.edu/catalogs/catalogsb.html> for the format.
+
+## Header (28 bytes)
+
+```text
+Integer*4 STAR0  Subtract from star number to get sequence number
+Integer*4 STAR1  First star number in file
+Integer*4 STARN  Number of stars in file (negative for J2000 coordinates)
+Integer*4 STNUM  0 if no star i.d. numbers are present
+                 1 if star i.d. numbers are in catalog file
+                 2 if star i.d. numbers are  in file
+Integer*4 MPROP  1 if proper motion is included
+                 0 if no proper motion is included
+                 2 if radial velocity is included
+Integer*4 NMAG   Number of magnitudes present (-1=J2000 instead of B1950)
+Integer*4 NBENT  Number of bytes per star entry
+```
+
+## Entry (32 bytes for `BSC5`)
+
+```text
+Real*4      XNO    Catalog number of star
+Real*8      SRA0   B1950 Right Ascension (radians)
+Real*8      SDEC0  B1950 Declination (radians)
+Character*2 IS     Spectral type (2 characters)
+Integer*2   MAG    V Magnitude * 100
+Real*4      XRPM   R.A. proper motion (radians per year)
+Real*4      XDPM   Dec. proper motion (radians per year)
+```
+
+> NOTE: the positions are J2000 when STARN is negative, as in `BSC5`.
+*/
+
+use super::yale::YaleStar;
+use crate::angle::Angle;
+use crate::parse::reader::decompress;
+use crate::parse::{ParseError, ParseErrorKind};
+
+use std::fs::File;
+use std::io::{BufReader, ErrorKind, Read};
+use std::ops::Range;
+use std::path::Path;
+
+/// Size of the file header in bytes
+const HEADER_SIZE: usize = 28;
+
+/// Upper bound on the size of an entry, used to detect the byte order
+const MAX_ENTRY_SIZE: usize = 256;
+
+/// Byte order of a binary catalog
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum ByteOrder {
+    Little,
+    Big,
+}
+
+impl ByteOrder {
+    fn i16(&self, b: &[u8]) -> i16 {
+        let b = [b[0], b[1]];
+        match self {
+            Self::Little => i16::from_le_bytes(b),
+            Self::Big => i16::from_be_bytes(b),
+        }
+    }
+    fn i32(&self, b: &[u8]) -> i32 {
+        let b = [b[0], b[1], b[2], b[3]];
+        match self {
+            Self::Little => i32::from_le_bytes(b),
+            Self::Big => i32::from_be_bytes(b),
+        }
+    }
+    fn f32(&self, b: &[u8]) -> f32 {
+        f32::from_bits(self.i32(b) as u32)
+    }
+    fn f64(&self, b: &[u8]) -> f64 {
+        let b = [b[0], b[1], b[2], b[3], b[4], b[5], b[6], b[7]];
+        match self {
+            Self::Little => f64::from_le_bytes(b),
+            Self::Big => f64::from_be_bytes(b),
+        }
+    }
+}
+
+/// Binary catalog header
+#[allow(non_snake_case)] // Copying field names from original data source
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Bsc5Header {
+    /// Subtract from star number to get sequence number
+    pub STAR0: i32,
+    /// First star number in file
+    pub STAR1: i32,
+    /// Number of stars in file (negative for J2000 coordinates)
+    pub STARN: i32,
+    /// Whether (and where) star i.d. numbers are present
+    pub STNUM: i32,
+    /// Proper motion (1) and radial velocity (2) flag
+    pub MPROP: i32,
+    /// Number of magnitudes present (negative for J2000 coordinates)
+    pub NMAG: i32,
+    /// Number of bytes per star entry
+    pub NBENT: i32,
+}
+
+impl Bsc5Header {
+    /// Number of stars in the file
+    pub fn len(&self) -> usize {
+        self.STARN.unsigned_abs() as usize
+    }
+
+    /// Whether the file has no stars
+    pub fn is_empty(&self) -> bool {
+        self.STARN == 0
+    }
+
+    /// Whether positions are J2000 rather than B1950
+    pub fn is_j2000(&self) -> bool {
+        self.STARN < 0
+    }
+
+    /// Whether the flags and entry size are consistent, which is only the case when read in the right byte order.
+    fn is_consistent(&self) -> bool {
+        (0..=2).contains(&self.STNUM)
+            && (0..=2).contains(&self.MPROP)
+            && (-10..=10).contains(&self.NMAG)
+            && (self.entry_size()..MAX_ENTRY_SIZE).contains(&(self.NBENT as usize))
+    }
+
+    /// Bytes used by the known fields of an entry
+    fn entry_size(&self) -> usize {
+        let id = if self.STNUM > 0 { 4 } else { 0 };
+        let proper_motion = if self.MPROP > 0 { 8 } else { 0 };
+        id + 8 + 8 + 2 + 2 * self.NMAG.unsigned_abs() as usize + proper_motion
+    }
+}
+
+/// Binary catalog record
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bsc5Star {
+    /// Catalog number of star (Harvard Revised Number for `BSC5`), if present
+    pub number: Option<f32>,
+    /// Right ascension in radians
+    pub right_ascension: f64,
+    /// Declination in radians
+    pub declination: f64,
+    /// Whether the position is J2000 rather than B1950
+    pub j2000: bool,
+    /// Spectral type (2 characters)
+    pub spectral_type: String,
+    /// V magnitudes (the first one is the visual magnitude)
+    pub magnitudes: Vec<f64>,
+    /// R.A. proper motion (radians per year), if present
+    pub proper_motion_ra: Option<f32>,
+    /// Dec. proper motion (radians per year), if present
+    pub proper_motion_dec: Option<f32>,
+}
+
+/**
+Iterator over the records of a binary catalog.
+
+The byte order is detected from the header.
+*/
+pub struct Bsc5Reader<R> {
+    reader: R,
+    header: Bsc5Header,
+    order: ByteOrder,
+    index: usize,
+}
+
+impl<R: Read> Bsc5Reader<R> {
+    /// Read the header of a binary catalog.
+    pub fn new(mut reader: R) -> Result<Self, ParseError> {
+        let mut bytes = [0_u8; HEADER_SIZE];
+        reader
+            .read_exact(&mut bytes)
+            .map_err(|why| match why.kind() {
+                ErrorKind::UnexpectedEof => {
+                    ParseError::field(0..HEADER_SIZE, "header", ParseErrorKind::Truncated)
+                }
+                _ => why.into(),
+            })?;
+
+        let read_header = |order: ByteOrder| {
+            let int = |i: usize| order.i32(&bytes[4 * i..4 * i + 4]);
+            Bsc5Header {
+                STAR0: int(0),
+                STAR1: int(1),
+                STARN: int(2),
+                STNUM: int(3),
+                MPROP: int(4),
+                NMAG: int(5),
+                NBENT: int(6),
+            }
+        };
+
+        let (header, order) = [ByteOrder::Little, ByteOrder::Big]
+            .into_iter()
+            .map(|order| (read_header(order), order))
+            .find(|(header, _)| header.is_consistent())
+            .ok_or_else(|| {
+                ParseError::field(
+                    24..28,
+                    "NBENT",
+                    ParseErrorKind::Invalid {
+                        text: format!("{:?}", &bytes[24..28]),
+                        reason: String::from("header is inconsistent in either byte order"),
+                    },
+                )
+            })?;
+
+        Ok(Self {
+            reader,
+            header,
+            order,
+            index: 0,
+        })
+    }
+
+    /// File header
+    pub fn header(&self) -> &Bsc5Header {
+        &self.header
+    }
+
+    fn parse_entry(&self, bytes: &[u8]) -> Bsc5Star {
+        let order = self.order;
+        let mut offset = 0;
+        let mut next = |size: usize| {
+            let field = &bytes[offset..offset + size];
+            offset += size;
+            field
+        };
+
+        let number = (self.header.STNUM > 0).then(|| order.f32(next(4)));
+        let right_ascension = order.f64(next(8));
+        let declination = order.f64(next(8));
+        let spectral_type = String::from_utf8_lossy(next(2)).trim().to_string();
+        let magnitudes = (0..self.header.NMAG.unsigned_abs())
+            .map(|_| order.i16(next(2)) as f64 / 100.0)
+            .collect();
+        let (proper_motion_ra, proper_motion_dec) = if self.header.MPROP > 0 {
+            (Some(order.f32(next(4))), Some(order.f32(next(4))))
+        } else {
+            (None, None)
+        };
+
+        Bsc5Star {
+            number,
+            right_ascension,
+            declination,
+            j2000: self.header.is_j2000(),
+            spectral_type,
+            magnitudes,
+            proper_motion_ra,
+            proper_motion_dec,
+        }
+    }
+}
+
+impl Bsc5Reader<Box<dyn std::io::BufRead>> {
+    /// Open a binary catalog file, decompressing gzip and bzip2 files transparently.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, ParseError> {
+        Self::new(decompress(BufReader::new(File::open(path)?))?)
+    }
+}
+
+impl<R: Read> Iterator for Bsc5Reader<R> {
+    type Item = Result<Bsc5Star, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.header.len() {
+            return None;
+        }
+
+        let size = self.header.NBENT as usize;
+        let start = HEADER_SIZE + self.index * size;
+        let columns: Range<usize> = start..start + size;
+        self.index += 1;
+
+        let mut bytes = vec![0_u8; size];
+        if let Err(why) = self.reader.read_exact(&mut bytes) {
+            // NOTE: stop after an error, the remaining entries can't be located.
+            self.index = self.header.len();
+            return Some(Err(match why.kind() {
+                ErrorKind::UnexpectedEof => {
+                    ParseError::field(columns, "entry", ParseErrorKind::Truncated)
+                }
+                _ => why.into(),
+            }));
+        }
+
+        Some(Ok(self.parse_entry(&bytes)))
+    }
+}
+
+impl From<Bsc5Star> for YaleStar {
+    /**
+    Fill the matching [`YaleStar`] fields from a binary record.
+
+    - Positions are only filled for J2000 files, rounded to the precision of `bsc5.dat`.
+    - Proper motions are converted to arc seconds per year, with the R.A. motion projected by cos(Dec) like `pmRA`.
+    */
+    fn from(star: Bsc5Star) -> Self {
+        let mut yale = YaleStar {
+            HR: star.number.map(|n| n.round() as usize),
+            SpType: Some(star.spectral_type.clone()).filter(|s| !s.is_empty()),
+            Vmag: star.magnitudes.first().copied(),
+            pmRA: star
+                .proper_motion_ra
+                .map(|pm| Angle::Radian(pm as f64).to_arcsec() * star.declination.cos()),
+            pmDe: star
+                .proper_motion_dec
+                .map(|pm| Angle::Radian(pm as f64).to_arcsec()),
+            ..Default::default()
+        };
+
+        if star.j2000 {
+            // Right ascension in tenths of a second of time
+            let tenths = (Angle::Radian(star.right_ascension)
+                .normalize_0_24h()
+                .to_hr()
+                * 36000.0)
+                .round() as usize
+                % (24 * 36000);
+            yale.RAh = Some(tenths / 36000);
+            yale.RAm = Some(tenths / 600 % 60);
+            yale.RAs = Some((tenths % 600) as f64 / 10.0);
+
+            // Declination in seconds of arc
+            let seconds = Angle::Radian(star.declination.abs()).to_arcsec().round() as usize;
+            yale.DE_ = Some(String::from(if star.declination < 0.0 { "-" } else { "+" }));
+            yale.DEd = Some(seconds / 3600);
+            yale.DEm = Some(seconds / 60 % 60);
+            yale.DEs = Some(seconds % 60);
+        }
+
+        yale
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::catalog::bsc5::*;
+    use crate::catalog::ValidParse;
+
+    /// HR 1 (from `bsc5.dat`) and a southern sample star in the binary format
+    fn bsc5(order: ByteOrder) -> Vec<u8> {
+        let mut bytes = vec![];
+        let int = |bytes: &mut Vec<u8>, i: i32| match order {
+            ByteOrder::Little => bytes.extend(i.to_le_bytes()),
+            ByteOrder::Big => bytes.extend(i.to_be_bytes()),
+        };
+        for i in [0, 1, -2, 1, 1, -1, 32] {
+            int(&mut bytes, i);
+        }
+
+        let arcsec = |a: f64| Angle::from_arcsec(a).to_rad();
+        // HR 1: 00 05 09.9 +45 13 45, 6.70, A1Vn, -0.012 -0.018
+        // Sample: 00 05 03.8 -00 30 11, 6.29, G9, +0.045 -0.060
+        for (hr, ra, dec, sp, mag, pmra, pmdec) in [
+            (
+                1.0_f32,
+                arcsec(15.0 * (5.0 * 60.0 + 9.9)),
+                arcsec(45.0 * 3600.0 + 13.0 * 60.0 + 45.0),
+                b"A1",
+                670_i16,
+                -0.012,
+                -0.018,
+            ),
+            (
+                2.0_f32,
+                arcsec(15.0 * (5.0 * 60.0 + 3.8)),
+                -arcsec(30.0 * 60.0 + 11.0),
+                b"G9",
+                629_i16,
+                0.045,
+                -0.060,
+            ),
+        ] {
+            let pmra = (arcsec(pmra) / dec.cos()) as f32;
+            let pmdec = arcsec(pmdec) as f32;
+            match order {
+                ByteOrder::Little => {
+                    bytes.extend(hr.to_le_bytes());
+                    bytes.extend(ra.to_le_bytes());
+                    bytes.extend(dec.to_le_bytes());
+                    bytes.extend(sp);
+                    bytes.extend(mag.to_le_bytes());
+                    bytes.extend(pmra.to_le_bytes());
+                    bytes.extend(pmdec.to_le_bytes());
+                }
+                ByteOrder::Big => {
+                    bytes.extend(hr.to_be_bytes());
+                    bytes.extend(ra.to_be_bytes());
+                    bytes.extend(dec.to_be_bytes());
+                    bytes.extend(sp);
+                    bytes.extend(mag.to_be_bytes());
+                    bytes.extend(pmra.to_be_bytes());
+                    bytes.extend(pmdec.to_be_bytes());
+                }
+            }
+        }
+        bytes
+    }
+
+    #[test]
+    fn bsc5_reader() {
+        for order in [ByteOrder::Little, ByteOrder::Big] {
+            let bytes = bsc5(order);
+            let reader = Bsc5Reader::new(bytes.as_slice()).unwrap();
+            assert_eq!(reader.order, order);
+            assert_eq!(reader.header().len(), 2);
+            assert!(reader.header().is_j2000());
+
+            let stars = reader.collect::<Result<Vec<_>, _>>().unwrap();
+            assert_eq!(stars.len(), 2);
+            assert_eq!(stars[0].number, Some(1.0));
+            assert_eq!(stars[0].spectral_type, "A1");
+            assert_eq!(stars[0].magnitudes, vec![6.70]);
+        }
+    }
+
+    #[test]
+    fn bsc5_to_yale() {
+        let stars = Bsc5Reader::new(bsc5(ByteOrder::Little).as_slice())
+            .unwrap()
+            .map(|star| YaleStar::from(star.unwrap()))
+            .collect::<Vec<_>>();
+
+        let hr1 = &stars[0];
+        assert!(hr1.is_valid_parse());
+        assert_eq!(hr1.HR, Some(1));
+        assert_eq!((hr1.RAh, hr1.RAm, hr1.RAs), (Some(0), Some(5), Some(9.9)));
+        assert_eq!(hr1.DE_.as_deref(), Some("+"));
+        assert_eq!((hr1.DEd, hr1.DEm, hr1.DEs), (Some(45), Some(13), Some(45)));
+        assert_eq!(hr1.Vmag, Some(6.70));
+        assert_float_absolute_eq!(hr1.pmRA.unwrap(), -0.012, 1e-6);
+        assert_float_absolute_eq!(hr1.pmDe.unwrap(), -0.018, 1e-6);
+
+        let hr2 = &stars[1];
+        assert_eq!(hr2.DE_.as_deref(), Some("-"));
+        assert_eq!((hr2.DEd, hr2.DEm, hr2.DEs), (Some(0), Some(30), Some(11)));
+    }
+
+    #[test]
+    fn bsc5_truncated() {
+        let bytes = bsc5(ByteOrder::Little);
+
+        let mut reader = Bsc5Reader::new(&bytes[..bytes.len() - 4]).unwrap();
+        assert!(reader.next().unwrap().is_ok());
+        match reader.next().unwrap() {
+            Err(ParseError::Field { columns, kind, .. }) => {
+                assert_eq!(columns, 60..92);
+                assert_eq!(kind, ParseErrorKind::Truncated);
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+        assert!(reader.next().is_none());
+
+        assert!(Bsc5Reader::new(&bytes[..10]).is_err());
+    }
+
+    #[test]
+    #[ignore]
+    fn catalog() {
+        let data_file = "data/Yale/BSC5";
+
+        if !std::path::Path::new(&data_file).exists() {
+            panic!("File \"{}\" doesn't exist. Please run \"get_data.sh\" to fetch the data required for this test.", &data_file)
+        };
+
+        let reader = Bsc5Reader::open(data_file).unwrap();
+        println!("Header: {:?}", reader.header());
+        let _stars = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        println!("Number of stars: {}", _stars.len());
+        println!(
+            "Last Star: {:?}",
+            YaleStar::from(_stars.last().unwrap().clone())
+        );
+    }
+}
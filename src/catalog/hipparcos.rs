@@ -374,67 +374,100 @@ H77         r_SpType   Spect_Type_Source  /Source of spectral type
 ```
 
 */
-use super::ValidParse;
-use crate::angle::{Angle, DegMinSec, HourMinSec, Sign};
+use super::util::{constrained_field, parse_sexagesimal};
+use crate::angle::{Angle, DegMinSec, HourMinSec};
 use crate::coord::{Declination, RightAscension};
+use crate::parse::fields::{parse_field, required};
+use crate::parse::{CatalogRecord, ParseError, ParseErrorKind, ValidParse};
 
 use std::convert::TryFrom;
-
-use crate::parse_trim;
+use std::ops::Range;
 
 /// Parse Hipparcos field
 macro_rules! parse_hipparcos_field {
     // Right ascension from float degree
-    (ra_deg, $s:expr) => {
-        match parse_hipparcos_field!(f64, $s) {
-            Some(deg) => Some(RightAscension(Angle::Degree(deg))),
-            None => None,
-        }
+    (ra_deg, $fields:expr, $i:literal) => {
+        parse_hipparcos_field!(angle_deg, RightAscension, $fields, $i)
     };
     // Declination from float degree
-    (dec_deg, $s:expr) => {
-        match parse_hipparcos_field!(f64, $s) {
-            Some(deg) => Some(Declination(Angle::Degree(deg))),
-            None => None,
-        }
+    (dec_deg, $fields:expr, $i:literal) => {
+        parse_hipparcos_field!(angle_deg, Declination, $fields, $i)
     };
     // Right ascension from "hh mm ss.s"
-    (ra_hms, $s:expr) => {{
-        let fields: Vec<&str> = $s.split(" ").collect();
-        RightAscension(Angle::from(HourMinSec(
-            Sign::Positive,
-            parse_hipparcos_field!(u32, fields[0]).unwrap(),
-            parse_hipparcos_field!(u32, fields[1]).unwrap(),
-            parse_hipparcos_field!(f64, fields[2]).unwrap(),
-        )))
-    }};
-    // Right ascension from "+dd mm ss.s"
-    (dec_dms, $s:expr) => {{
-        let fields: Vec<&str> = $s.split(" ").collect();
-        let sign: Sign = match &fields[0][0..1] {
-            "+" => Sign::Positive,
-            "-" => Sign::Negative,
-            _ => panic!(),
-        };
-        Declination(Angle::from(DegMinSec(
-            sign,
-            parse_hipparcos_field!(u32, fields[0][1..]).unwrap(),
-            parse_hipparcos_field!(u32, fields[1]).unwrap(),
-            parse_hipparcos_field!(f64, fields[2]).unwrap(),
-        )))
-    }};
+    (ra_hms, $fields:expr, $i:literal) => {
+        parse_hipparcos_field!(angle_ams, HourMinSec, RightAscension, $fields, $i)
+    };
+    // Declination from "+dd mm ss.s"
+    (dec_dms, $fields:expr, $i:literal) => {
+        parse_hipparcos_field!(angle_ams, DegMinSec, Declination, $fields, $i)
+    };
+    // Constrained angle from float degree
+    (angle_deg, $C:ty, $fields:expr, $i:literal) => {
+        hipparcos_field(&$fields, $i, concat!("H", $i)).and_then(|(columns, text)| {
+            parse_field::<f64>(text, columns.clone(), concat!("H", $i))?
+                .map(|deg| {
+                    constrained_field::<$C>(Angle::Degree(deg), text, columns, concat!("H", $i))
+                })
+                .transpose()
+        })
+    };
+    // Required constrained angle from sexagesimal
+    (angle_ams, $S:ident, $C:ty, $fields:expr, $i:literal) => {
+        hipparcos_field(&$fields, $i, concat!("H", $i)).and_then(|(columns, text)| {
+            match parse_sexagesimal(text, ' ', columns.clone(), concat!("H", $i))? {
+                Some((sign, major, minor, second)) => constrained_field::<$C>(
+                    Angle::from($S(sign, major, minor, second)),
+                    text,
+                    columns,
+                    concat!("H", $i),
+                ),
+                None => Err(ParseError::field(
+                    columns,
+                    concat!("H", $i),
+                    ParseErrorKind::Missing,
+                )),
+            }
+        })
+    };
     // Parse a given type with the field trimmed of spaces
-    ($T:ty, $s:expr) => {
-        parse_trim!($T, $s)
+    ($T:ty, $fields:expr, $i:literal) => {
+        hipparcos_field(&$fields, $i, concat!("H", $i))
+            .and_then(|(columns, text)| parse_field::<$T>(text, columns, concat!("H", $i)))
     };
 }
 
-/**
-
-
+/// Split a record into its `|` separated fields along with their byte ranges.
+fn split_fields(s: &str) -> Vec<(Range<usize>, &str)> {
+    let mut start = 0;
+    s.split('|')
+        .map(|field| {
+            let columns = start..start + field.len();
+            start = columns.end + 1;
+            (columns, field)
+        })
+        .collect()
+}
 
-*/
+/// Get the `i`th field of a record, reporting a truncated line if there are not enough fields.
+fn hipparcos_field<'a>(
+    fields: &[(Range<usize>, &'a str)],
+    i: usize,
+    label: &'static str,
+) -> Result<(Range<usize>, &'a str), ParseError> {
+    match fields.get(i) {
+        Some((columns, text)) => Ok((columns.clone(), text)),
+        None => {
+            let end = fields.last().map_or(0, |(columns, _)| columns.end);
+            Err(ParseError::field(
+                end..end,
+                label,
+                ParseErrorKind::Truncated,
+            ))
+        }
+    }
+}
 
+/// [Hipparcos Catalog](https://heasarc.gsfc.nasa.gov/W3Browse/all/hipparcos.html) record
 #[allow(non_snake_case)] // Copying field names from original data source
 #[derive(Debug, Clone)]
 pub struct HipparcosStar {
@@ -671,94 +704,92 @@ pub struct HipparcosStar {
 }
 
 impl TryFrom<String> for HipparcosStar {
-    type Error = ();
+    type Error = ParseError;
 
     fn try_from(s: String) -> Result<Self, Self::Error> {
-        let fields: Vec<&str> = s.split('|').collect();
+        let fields = split_fields(&s);
         let star = Self {
-            catalog: parse_hipparcos_field!(String, fields[0]), //          [H] Catalogue (H=Hipparcos)               (H0)
-            HIP: parse_hipparcos_field!(usize, fields[1]), //               Identifier (HIP number)                   (H1)
-            Proxy: parse_hipparcos_field!(String, fields[2]), //           *[HT] Proximity flag                       (H2)
-            right_ascension: parse_hipparcos_field!(ra_hms, fields[3]), //  Right ascension in h m s, ICRS (J1991.25) (H3)
-            declination: parse_hipparcos_field!(dec_dms, fields[4]), //     Declination in deg ' ", ICRS (J1991.25)   (H4)
-            Vmag: parse_hipparcos_field!(f64, fields[5]), //                ? Magnitude in Johnson V                  (H5)
-            VarFlag: parse_hipparcos_field!(String, fields[6]), //         *[1,3]? Coarse variability flag            (H6)
-            r_Vmag: parse_hipparcos_field!(String, fields[7]), //          *[GHT] Source of magnitude                 (H7)
-            RAdeg: parse_hipparcos_field!(ra_deg, fields[8]), //           *? alpha, degrees (ICRS, Epoch=J1991.25)   (H8)
-            DEdeg: parse_hipparcos_field!(dec_deg, fields[9]), //          *? delta, degrees (ICRS, Epoch=J1991.25)   (H9)
-            AstroRef: parse_hipparcos_field!(String, fields[10]), //       *[*+A-Z] Reference flag for astrometry    (H10)
-            Plx: parse_hipparcos_field!(f64, fields[11]), //                ? Trigonometric parallax                 (H11)
-            pmRA: parse_hipparcos_field!(f64, fields[12]), //              *? Proper motion mu_alpha.cos(delta), ICRS(H12)
-            pmDE: parse_hipparcos_field!(f64, fields[13]), //              *? Proper motion mu_delta, ICRS           (H13)
-            e_RAdeg: parse_hipparcos_field!(f64, fields[14]), //           *? Standard error in RA*cos(DEdeg)        (H14)
-            e_DEdeg: parse_hipparcos_field!(f64, fields[15]), //           *? Standard error in DE                   (H15)
-            e_Plx: parse_hipparcos_field!(f64, fields[16]), //              ? Standard error in Plx                  (H16)
-            e_pmRA: parse_hipparcos_field!(f64, fields[17]), //             ? Standard error in pmRA                 (H17)
-            e_pmDE: parse_hipparcos_field!(f64, fields[18]), //             ? Standard error in pmDE                 (H18)
-            DE_RA: parse_hipparcos_field!(f64, fields[19]), //              [-1/1]? Correlation, DE/RA*cos(delta)    (H19)
-            Plx_RA: parse_hipparcos_field!(f64, fields[20]), //             [-1/1]? Correlation, Plx/RA*cos(delta)   (H20)
-            Plx_DE: parse_hipparcos_field!(f64, fields[21]), //             [-1/1]? Correlation, Plx/DE              (H21)
-            pmRA_RA: parse_hipparcos_field!(f64, fields[22]), //            [-1/1]? Correlation, pmRA/RA*cos(delta)  (H22)
-            pmRA_DE: parse_hipparcos_field!(f64, fields[23]), //            [-1/1]? Correlation, pmRA/DE             (H23)
-            pmRA_Plx: parse_hipparcos_field!(f64, fields[24]), //           [-1/1]? Correlation, pmRA/Plx            (H24)
-            pmDE_RA: parse_hipparcos_field!(f64, fields[25]), //            [-1/1]? Correlation, pmDE/RA*cos(delta)  (H25)
-            pmDE_DE: parse_hipparcos_field!(f64, fields[26]), //            [-1/1]? Correlation, pmDE/DE             (H26)
-            pmDE_Plx: parse_hipparcos_field!(f64, fields[27]), //           [-1/1]? Correlation, pmDE/Plx            (H27)
-            pmDE_pmRA: parse_hipparcos_field!(f64, fields[28]), //          [-1/1]? Correlation, pmDE/pmRA           (H28)
-            F1: parse_hipparcos_field!(usize, fields[29]), //               ? Percentage of rejected data            (H29)
-            F2: parse_hipparcos_field!(f64, fields[30]), //                *? Goodness-of-fit parameter              (H30)
-            BTmag: parse_hipparcos_field!(f64, fields[32]), //              ? Mean BT magnitude                      (H32)
-            e_BTmag: parse_hipparcos_field!(f64, fields[33]), //            ? Standard error on BTmag                (H33)
-            VTmag: parse_hipparcos_field!(f64, fields[34]), //              ? Mean VT magnitude                      (H34)
-            e_VTmag: parse_hipparcos_field!(f64, fields[35]), //            ? Standard error on VTmag                (H35)
-            m_BTmag: parse_hipparcos_field!(String, fields[36]), //        *[A-Z*-] Reference flag for BT and VTmag  (H36)
-            B_V: parse_hipparcos_field!(f64, fields[37]), //                ? Johnson B-V colour                     (H37)
-            e_B_V: parse_hipparcos_field!(f64, fields[38]), //              ? Standard error on B-V                  (H38)
-            r_B_V: parse_hipparcos_field!(String, fields[39]), //           [GT] Source of B-V from Ground or Tycho  (H39)
-            V_I: parse_hipparcos_field!(f64, fields[40]), //                ? Colour index in Cousins' system        (H40)
-            e_V_I: parse_hipparcos_field!(f64, fields[41]), //              ? Standard error on V-I                  (H41)
-            r_V_I: parse_hipparcos_field!(String, fields[42]), //          *[A-T] Source of V-I                      (H42)
-            CombMag: parse_hipparcos_field!(String, fields[43]), //         [*] Flag for combined Vmag, B-V, V-I     (H43)
-            Hpmag: parse_hipparcos_field!(f64, fields[44]), //             *? Median magnitude in Hipparcos system   (H44)
-            e_Hpmag: parse_hipparcos_field!(f64, fields[45]), //           *? Standard error on Hpmag                (H45)
-            Hpscat: parse_hipparcos_field!(f64, fields[46]), //             ? Scatter on Hpmag                       (H46)
-            o_Hpmag: parse_hipparcos_field!(usize, fields[47]), //          ? Number of observations for Hpmag       (H47)
-            m_Hpmag: parse_hipparcos_field!(String, fields[48]), //        *[A-Z*-] Reference flag for Hpmag         (H48)
-            Hpmax: parse_hipparcos_field!(f64, fields[49]), //              ? Hpmag at maximum (5th percentile)      (H49)
-            HPmin: parse_hipparcos_field!(f64, fields[50]), //              ? Hpmag at minimum (95th percentile)     (H50)
-            Period: parse_hipparcos_field!(f64, fields[51]), //             ? Variability period (days)              (H51)
-            HvarType: parse_hipparcos_field!(String, fields[52]), //       *[CDMPRU]? variability type               (H52)
-            moreVar: parse_hipparcos_field!(String, fields[53]), //        *[12] Additional data about variability   (H53)
-            morePhoto: parse_hipparcos_field!(String, fields[54]), //       [ABC] Light curve Annex                  (H54)
-            CCDM: parse_hipparcos_field!(String, fields[55]), //            CCDM identifier                          (H55)
-            n_CCDM: parse_hipparcos_field!(String, fields[56]), //         *[HIM] Historical status flag             (H56)
-            Nsys: parse_hipparcos_field!(usize, fields[57]), //             ? Number of entries with same CCDM       (H57)
-            Ncomp: parse_hipparcos_field!(usize, fields[58]), //            ? Number of components in this entry     (H58)
-            MultFlag: parse_hipparcos_field!(String, fields[59]), //       *[CGOVX] Double/Multiple Systems flag     (H59)
-            Source: parse_hipparcos_field!(String, fields[60]), //         *[PFILS] Astrometric source flag          (H60)
-            Qual: parse_hipparcos_field!(String, fields[61]), //           *[ABCDS] Solution quality                 (H61)
-            m_HIP: parse_hipparcos_field!(String, fields[62]), //           Component identifiers                    (H62)
-            theta: parse_hipparcos_field!(usize, fields[63]), //            ? Position angle between components      (H63)
-            rho: parse_hipparcos_field!(f64, fields[64]), //                ? Angular separation between components  (H64)
-            e_rho: parse_hipparcos_field!(f64, fields[65]), //              ? Standard error on rho                  (H65)
-            dHp: parse_hipparcos_field!(f64, fields[66]), //                ? Magnitude difference of components     (H66)
-            e_dHp: parse_hipparcos_field!(f64, fields[67]), //              ? Standard error on dHp                  (H67)
-            Survey: parse_hipparcos_field!(String, fields[68]), //          [S] Flag indicating a Survey Star        (H68)
-            Chart: parse_hipparcos_field!(String, fields[69]), //          *[DG] Identification Chart                (H69)
-            Notes: parse_hipparcos_field!(String, fields[70]), //          *[DGPWXYZ] Existence of notes             (H70)
-            HD: parse_hipparcos_field!(usize, fields[71]), //               [1/359083]? HD number <III/135>          (H71)
-            BD: parse_hipparcos_field!(String, fields[72]), //              Bonner DM <I/119>, <I/122>               (H72)
-            CoD: parse_hipparcos_field!(String, fields[73]), //             Cordoba Durchmusterung (DM) <I/114>      (H73)
-            CPD: parse_hipparcos_field!(String, fields[74]), //             Cape Photographic DM <I/108>             (H74)
-            V_I_red: parse_hipparcos_field!(f64, fields[75]), //            V-I used for reductions                  (H75)
-            SpType: parse_hipparcos_field!(String, fields[76]), //          Spectral type                            (H76)
-            r_SpType: parse_hipparcos_field!(String, fields[77]), //       *[1234GKSX]? Source of spectral type      (H77)
+            catalog: parse_hipparcos_field!(String, fields, 0)?, //          [H] Catalogue (H=Hipparcos)               (H0)
+            HIP: parse_hipparcos_field!(usize, fields, 1)?, //               Identifier (HIP number)                   (H1)
+            Proxy: parse_hipparcos_field!(String, fields, 2)?, //           *[HT] Proximity flag                       (H2)
+            right_ascension: parse_hipparcos_field!(ra_hms, fields, 3)?, //  Right ascension in h m s, ICRS (J1991.25) (H3)
+            declination: parse_hipparcos_field!(dec_dms, fields, 4)?, //     Declination in deg ' ", ICRS (J1991.25)   (H4)
+            Vmag: parse_hipparcos_field!(f64, fields, 5)?, //                ? Magnitude in Johnson V                  (H5)
+            VarFlag: parse_hipparcos_field!(String, fields, 6)?, //         *[1,3]? Coarse variability flag            (H6)
+            r_Vmag: parse_hipparcos_field!(String, fields, 7)?, //          *[GHT] Source of magnitude                 (H7)
+            RAdeg: parse_hipparcos_field!(ra_deg, fields, 8)?, //           *? alpha, degrees (ICRS, Epoch=J1991.25)   (H8)
+            DEdeg: parse_hipparcos_field!(dec_deg, fields, 9)?, //          *? delta, degrees (ICRS, Epoch=J1991.25)   (H9)
+            AstroRef: parse_hipparcos_field!(String, fields, 10)?, //       *[*+A-Z] Reference flag for astrometry    (H10)
+            Plx: parse_hipparcos_field!(f64, fields, 11)?, //                ? Trigonometric parallax                 (H11)
+            pmRA: parse_hipparcos_field!(f64, fields, 12)?, //              *? Proper motion mu_alpha.cos(delta), ICRS(H12)
+            pmDE: parse_hipparcos_field!(f64, fields, 13)?, //              *? Proper motion mu_delta, ICRS           (H13)
+            e_RAdeg: parse_hipparcos_field!(f64, fields, 14)?, //           *? Standard error in RA*cos(DEdeg)        (H14)
+            e_DEdeg: parse_hipparcos_field!(f64, fields, 15)?, //           *? Standard error in DE                   (H15)
+            e_Plx: parse_hipparcos_field!(f64, fields, 16)?, //              ? Standard error in Plx                  (H16)
+            e_pmRA: parse_hipparcos_field!(f64, fields, 17)?, //             ? Standard error in pmRA                 (H17)
+            e_pmDE: parse_hipparcos_field!(f64, fields, 18)?, //             ? Standard error in pmDE                 (H18)
+            DE_RA: parse_hipparcos_field!(f64, fields, 19)?, //              [-1/1]? Correlation, DE/RA*cos(delta)    (H19)
+            Plx_RA: parse_hipparcos_field!(f64, fields, 20)?, //             [-1/1]? Correlation, Plx/RA*cos(delta)   (H20)
+            Plx_DE: parse_hipparcos_field!(f64, fields, 21)?, //             [-1/1]? Correlation, Plx/DE              (H21)
+            pmRA_RA: parse_hipparcos_field!(f64, fields, 22)?, //            [-1/1]? Correlation, pmRA/RA*cos(delta)  (H22)
+            pmRA_DE: parse_hipparcos_field!(f64, fields, 23)?, //            [-1/1]? Correlation, pmRA/DE             (H23)
+            pmRA_Plx: parse_hipparcos_field!(f64, fields, 24)?, //           [-1/1]? Correlation, pmRA/Plx            (H24)
+            pmDE_RA: parse_hipparcos_field!(f64, fields, 25)?, //            [-1/1]? Correlation, pmDE/RA*cos(delta)  (H25)
+            pmDE_DE: parse_hipparcos_field!(f64, fields, 26)?, //            [-1/1]? Correlation, pmDE/DE             (H26)
+            pmDE_Plx: parse_hipparcos_field!(f64, fields, 27)?, //           [-1/1]? Correlation, pmDE/Plx            (H27)
+            pmDE_pmRA: parse_hipparcos_field!(f64, fields, 28)?, //          [-1/1]? Correlation, pmDE/pmRA           (H28)
+            F1: parse_hipparcos_field!(usize, fields, 29)?, //               ? Percentage of rejected data            (H29)
+            F2: parse_hipparcos_field!(f64, fields, 30)?, //                *? Goodness-of-fit parameter              (H30)
+            BTmag: parse_hipparcos_field!(f64, fields, 32)?, //              ? Mean BT magnitude                      (H32)
+            e_BTmag: parse_hipparcos_field!(f64, fields, 33)?, //            ? Standard error on BTmag                (H33)
+            VTmag: parse_hipparcos_field!(f64, fields, 34)?, //              ? Mean VT magnitude                      (H34)
+            e_VTmag: parse_hipparcos_field!(f64, fields, 35)?, //            ? Standard error on VTmag                (H35)
+            m_BTmag: parse_hipparcos_field!(String, fields, 36)?, //        *[A-Z*-] Reference flag for BT and VTmag  (H36)
+            B_V: parse_hipparcos_field!(f64, fields, 37)?, //                ? Johnson B-V colour                     (H37)
+            e_B_V: parse_hipparcos_field!(f64, fields, 38)?, //              ? Standard error on B-V                  (H38)
+            r_B_V: parse_hipparcos_field!(String, fields, 39)?, //           [GT] Source of B-V from Ground or Tycho  (H39)
+            V_I: parse_hipparcos_field!(f64, fields, 40)?, //                ? Colour index in Cousins' system        (H40)
+            e_V_I: parse_hipparcos_field!(f64, fields, 41)?, //              ? Standard error on V-I                  (H41)
+            r_V_I: parse_hipparcos_field!(String, fields, 42)?, //          *[A-T] Source of V-I                      (H42)
+            CombMag: parse_hipparcos_field!(String, fields, 43)?, //         [*] Flag for combined Vmag, B-V, V-I     (H43)
+            Hpmag: parse_hipparcos_field!(f64, fields, 44)?, //             *? Median magnitude in Hipparcos system   (H44)
+            e_Hpmag: parse_hipparcos_field!(f64, fields, 45)?, //           *? Standard error on Hpmag                (H45)
+            Hpscat: parse_hipparcos_field!(f64, fields, 46)?, //             ? Scatter on Hpmag                       (H46)
+            o_Hpmag: parse_hipparcos_field!(usize, fields, 47)?, //          ? Number of observations for Hpmag       (H47)
+            m_Hpmag: parse_hipparcos_field!(String, fields, 48)?, //        *[A-Z*-] Reference flag for Hpmag         (H48)
+            Hpmax: parse_hipparcos_field!(f64, fields, 49)?, //              ? Hpmag at maximum (5th percentile)      (H49)
+            HPmin: parse_hipparcos_field!(f64, fields, 50)?, //              ? Hpmag at minimum (95th percentile)     (H50)
+            Period: parse_hipparcos_field!(f64, fields, 51)?, //             ? Variability period (days)              (H51)
+            HvarType: parse_hipparcos_field!(String, fields, 52)?, //       *[CDMPRU]? variability type               (H52)
+            moreVar: parse_hipparcos_field!(String, fields, 53)?, //        *[12] Additional data about variability   (H53)
+            morePhoto: parse_hipparcos_field!(String, fields, 54)?, //       [ABC] Light curve Annex                  (H54)
+            CCDM: parse_hipparcos_field!(String, fields, 55)?, //            CCDM identifier                          (H55)
+            n_CCDM: parse_hipparcos_field!(String, fields, 56)?, //         *[HIM] Historical status flag             (H56)
+            Nsys: parse_hipparcos_field!(usize, fields, 57)?, //             ? Number of entries with same CCDM       (H57)
+            Ncomp: parse_hipparcos_field!(usize, fields, 58)?, //            ? Number of components in this entry     (H58)
+            MultFlag: parse_hipparcos_field!(String, fields, 59)?, //       *[CGOVX] Double/Multiple Systems flag     (H59)
+            Source: parse_hipparcos_field!(String, fields, 60)?, //         *[PFILS] Astrometric source flag          (H60)
+            Qual: parse_hipparcos_field!(String, fields, 61)?, //           *[ABCDS] Solution quality                 (H61)
+            m_HIP: parse_hipparcos_field!(String, fields, 62)?, //           Component identifiers                    (H62)
+            theta: parse_hipparcos_field!(usize, fields, 63)?, //            ? Position angle between components      (H63)
+            rho: parse_hipparcos_field!(f64, fields, 64)?, //                ? Angular separation between components  (H64)
+            e_rho: parse_hipparcos_field!(f64, fields, 65)?, //              ? Standard error on rho                  (H65)
+            dHp: parse_hipparcos_field!(f64, fields, 66)?, //                ? Magnitude difference of components     (H66)
+            e_dHp: parse_hipparcos_field!(f64, fields, 67)?, //              ? Standard error on dHp                  (H67)
+            Survey: parse_hipparcos_field!(String, fields, 68)?, //          [S] Flag indicating a Survey Star        (H68)
+            Chart: parse_hipparcos_field!(String, fields, 69)?, //          *[DG] Identification Chart                (H69)
+            Notes: parse_hipparcos_field!(String, fields, 70)?, //          *[DGPWXYZ] Existence of notes             (H70)
+            HD: parse_hipparcos_field!(usize, fields, 71)?, //               [1/359083]? HD number <III/135>          (H71)
+            BD: parse_hipparcos_field!(String, fields, 72)?, //              Bonner DM <I/119>, <I/122>               (H72)
+            CoD: parse_hipparcos_field!(String, fields, 73)?, //             Cordoba Durchmusterung (DM) <I/114>      (H73)
+            CPD: parse_hipparcos_field!(String, fields, 74)?, //             Cape Photographic DM <I/108>             (H74)
+            V_I_red: parse_hipparcos_field!(f64, fields, 75)?, //            V-I used for reductions                  (H75)
+            SpType: parse_hipparcos_field!(String, fields, 76)?, //          Spectral type                            (H76)
+            r_SpType: parse_hipparcos_field!(String, fields, 77)?, //       *[1234GKSX]? Source of spectral type      (H77)
         };
-        if star.is_valid_parse() {
-            Ok(star)
-        } else {
-            Err(())
-        }
+        required(&s, &star.HIP, fields[1].0.clone(), "H1")?;
+        // NOTE: a few stars have no astrometric solution (blank H8 and H9), those fail `ValidParse` instead.
+        Ok(star)
     }
 }
 
@@ -768,10 +799,12 @@ impl ValidParse for HipparcosStar {
     }
 }
 
+impl CatalogRecord for HipparcosStar {}
+
 #[cfg(test)]
 mod tests {
     use crate::catalog::hipparcos::*;
-    use crate::parse_catalog;
+    use crate::catalog::CatalogReader;
 
     #[test]
     fn hipparcosstar_from() {
@@ -779,6 +812,34 @@ mod tests {
         HipparcosStar::try_from(s).unwrap();
     }
 
+    #[test]
+    fn hipparcosstar_errors() {
+        match HipparcosStar::try_from(String::from("H|           1| |00 00 00.22|+01 05 20.4")) {
+            Err(ParseError::Field { field, kind, .. }) => {
+                assert_eq!(field, "H5");
+                assert_eq!(kind, ParseErrorKind::Truncated);
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+
+        match HipparcosStar::try_from(String::from("H|           1| |00 00 00.22|+01 05|")) {
+            Err(ParseError::Field { field, columns, .. }) => {
+                assert_eq!(field, "H4");
+                assert_eq!(columns, 29..35);
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+
+        // Declinations beyond the poles are rejected rather than panicking
+        match HipparcosStar::try_from(String::from("H|           1| |00 00 00.22|+91 05 20.4|")) {
+            Err(ParseError::Field { field, kind, .. }) => {
+                assert_eq!(field, "H4");
+                assert!(matches!(kind, ParseErrorKind::Invalid { .. }));
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
     #[test]
     #[ignore]
     fn catalog() {
@@ -788,8 +849,14 @@ mod tests {
             panic!("File \"{}\" doesn't exist. Please run \"get_data.sh\" to fetch the data required for this test.", &data_file)
         };
 
-        let _stars = parse_catalog!(HipparcosStar, Path::new(&data_file), None);
-        println!("Number of stars: {}", _stars.len());
-        println!("Last Star: {:?}", _stars.last().unwrap());
+        // Stream the records rather than holding the whole catalog in memory
+        let mut count = 0;
+        let mut last = None;
+        for star in CatalogReader::<_, HipparcosStar>::open(data_file).unwrap() {
+            last = Some(star.unwrap());
+            count += 1;
+        }
+        println!("Number of stars: {}", count);
+        println!("Last Star: {:?}", last.unwrap());
     }
 }
@@ -3,38 +3,18 @@
 
 > NOTE: run the `get_data.sh` script to get the tests to pass.
 */
-use super::ValidParse;
-use crate::angle::{DegMinSec, HourMinSec, Sign};
-use crate::parse_trim;
+use super::util::parse_sexagesimal;
+use crate::angle::{DegMinSec, HourMinSec};
+use crate::parse::fields::{not_truncated, parse_field, parse_fixed, required, slice_field};
+use crate::parse::{CatalogRecord, ParseError, ValidParse};
+use std::ops::Range;
 use unicode_segmentation::UnicodeSegmentation;
 
-/// Parse an arc/minute/second field.
-macro_rules! parse_ams {
-    // Hour/Minute/Second
-    (hms, $s:expr) => {{
-        let fields: Vec<&str> = $s.split("_").collect();
-        Some(HourMinSec(
-            Sign::Positive,
-            parse_trim!(u32, fields[0]).unwrap(),
-            parse_trim!(u32, fields[1]).unwrap(),
-            parse_trim!(f64, fields[2]).unwrap(),
-        ))
-    }};
-    // Degree/Minute/Second
-    (dms, $s:expr) => {{
-        let fields: Vec<&str> = $s.split("_").collect();
-        let sign: Sign = match &fields[0][0..1] {
-            " " => Sign::Positive,
-            "-" => Sign::Negative,
-            _ => panic!(),
-        };
-        Some(DegMinSec(
-            sign,
-            parse_trim!(u32, fields[0][1..]).unwrap(),
-            parse_trim!(u32, fields[1]).unwrap(),
-            parse_trim!(f64, fields[2]).unwrap(),
-        ))
-    }};
+/// Extract a field counted in graphemes rather than bytes. Graphemes past the end of the line are blank.
+fn grapheme_field(g: &[&str], columns: Range<usize>) -> String {
+    let end = columns.end.min(g.len());
+    let start = columns.start.min(end);
+    g[start..end].concat()
 }
 
 /**
@@ -190,46 +170,51 @@ pub struct OSBSCStar {
 }
 
 impl TryFrom<String> for OSBSCStar {
-    type Error = ();
+    type Error = ParseError;
 
     fn try_from(s: String) -> Result<Self, Self::Error> {
         let g = UnicodeSegmentation::graphemes(s.as_str(), true).collect::<Vec<&str>>();
         let star = Self {
-            Hipparcos_id: parse_trim!(usize, s[0..6]),
-
-            right_ascension_hms: parse_ams!(hms, s[8..24]),
-            declination_dms: parse_ams!(dms, s[26..42]),
-            right_ascension_rad: parse_trim!(f64, s[44..56]),
-            declination_rad: parse_trim!(f64, s[58..71]),
-            parallax: parse_trim!(f64, s[72..79]),
-            proper_motion_ra: parse_trim!(f64, s[80..88]),
-            proper_motion_dec: parse_trim!(f64, s[89..97]),
-            radial_velocity: parse_trim!(f64, s[98..105]),
-            right_ascension_rad_err: parse_trim!(f64, s[106..112]),
-            declination_rad_err: parse_trim!(f64, s[113..119]),
-            parallax_err: parse_trim!(f64, s[120..126]),
-            proper_motion_ra_err: parse_trim!(f64, s[127..133]),
-            proper_motion_dec_err: parse_trim!(f64, s[134..140]),
-            radial_velocity_err: parse_trim!(f64, s[141..146]),
-            V_magnitude: parse_trim!(f64, s[147..152]),
-            variability_flag: parse_trim!(usize, s[153..154]),
-            spectral_type: parse_trim!(String, s[155..167]),
-            BV_magnitude: parse_trim!(f64, s[168..174]),
-            multiplicity_flag: parse_trim!(String, s[175..176]),
-            CCDM_id: parse_trim!(String, s[177..187]),
-            HD_id: parse_trim!(usize, s[188..194]),
-            Yale_id: parse_trim!(usize, s[195..199]),
-            Bayer_id: parse_trim!(String, &g[200..207].join("").to_string()),
-            Flamsteed_id: parse_trim!(String, &g[208..215].join("").to_string()),
-            proper_name: parse_trim!(String, &g[216..230].join("").to_string()),
-            constellation: parse_trim!(String, &g[231..234].join("").to_string()),
-            provenence: parse_trim!(String, &g[235..262].join("").to_string()),
+            Hipparcos_id: parse_fixed(&s, 0..6, "01")?,
+            right_ascension_hms: parse_sexagesimal(
+                slice_field(&s, 8..24, "02")?,
+                '_',
+                8..24,
+                "02",
+            )?
+            .map(|(sign, hour, min, sec)| HourMinSec(sign, hour, min, sec)),
+            declination_dms: parse_sexagesimal(slice_field(&s, 26..42, "03")?, '_', 26..42, "03")?
+                .map(|(sign, deg, min, sec)| DegMinSec(sign, deg, min, sec)),
+            right_ascension_rad: parse_fixed(&s, 44..56, "04")?,
+            declination_rad: parse_fixed(&s, 58..71, "05")?,
+            parallax: parse_fixed(&s, 72..79, "06")?,
+            proper_motion_ra: parse_fixed(&s, 80..88, "07")?,
+            proper_motion_dec: parse_fixed(&s, 89..97, "08")?,
+            radial_velocity: parse_fixed(&s, 98..105, "09")?,
+            right_ascension_rad_err: parse_fixed(&s, 106..112, "10")?,
+            declination_rad_err: parse_fixed(&s, 113..119, "11")?,
+            parallax_err: parse_fixed(&s, 120..126, "12")?,
+            proper_motion_ra_err: parse_fixed(&s, 127..133, "13")?,
+            proper_motion_dec_err: parse_fixed(&s, 134..140, "14")?,
+            radial_velocity_err: parse_fixed(&s, 141..146, "15")?,
+            V_magnitude: parse_fixed(&s, 147..152, "16")?,
+            variability_flag: parse_fixed(&s, 153..154, "17")?,
+            spectral_type: parse_fixed(&s, 155..167, "18")?,
+            BV_magnitude: parse_fixed(&s, 168..174, "19")?,
+            multiplicity_flag: parse_fixed(&s, 175..176, "20")?,
+            CCDM_id: parse_fixed(&s, 177..187, "21")?,
+            HD_id: parse_fixed(&s, 188..194, "22")?,
+            Yale_id: parse_fixed(&s, 195..199, "23")?,
+            Bayer_id: parse_field(&grapheme_field(&g, 200..207), 200..207, "24")?,
+            Flamsteed_id: parse_field(&grapheme_field(&g, 208..215), 208..215, "25")?,
+            proper_name: parse_field(&grapheme_field(&g, 216..230), 216..230, "26")?,
+            constellation: parse_field(&grapheme_field(&g, 231..234), 231..234, "27")?,
+            provenence: parse_field(&grapheme_field(&g, 235..262), 235..262, "28")?,
         };
-        if star.is_valid_parse() {
-            Ok(star)
-        } else {
-            Err(())
-        }
+        required(&s, &star.Hipparcos_id, 0..6, "01")?;
+        not_truncated(&s, 44..56, "04")?;
+        not_truncated(&s, 58..71, "05")?;
+        Ok(star)
     }
 }
 
@@ -241,10 +226,13 @@ impl ValidParse for OSBSCStar {
     }
 }
 
+impl CatalogRecord for OSBSCStar {}
+
 #[cfg(test)]
 mod tests {
+    use crate::angle::Sign;
     use crate::catalog::osbsc::*;
-    use crate::parse_catalog;
+    use crate::catalog::parse_catalog;
 
     #[test]
     fn osbscstar_from() {
@@ -253,7 +241,7 @@ mod tests {
         assert_eq!(star.Hipparcos_id.unwrap(), 88_usize);
         assert_eq!(
             star.right_ascension_hms.unwrap(),
-            HourMinSec(Sign::Positive, 00, 01, 04.5982692)
+            HourMinSec(Sign::Positive, 0, 1, 4.5982692)
         );
         assert_eq!(
             star.declination_dms.unwrap(),
@@ -298,14 +286,11 @@ mod tests {
             panic!("File \"{}\" doesn't exist. Please run \"get_data.sh\" to fetch the data required for this test.", &data_file)
         };
 
-        let _stars = parse_catalog!(
-            OSBSCStar,
-            Path::new(&data_file),
-            // NOTE: it seems like we don't need to pad this catalog even though it has no delimiters.
-            // In case it breaks in the future: Some(262)
-            None
-        );
+        // NOTE: it seems like we don't need to pad this catalog even though it has no delimiters.
+        // In case it breaks in the future: `const PAD: Option<usize> = Some(262)` on `OSBSCStar`.
+        let _stars = parse_catalog::<OSBSCStar>(data_file).unwrap();
         println!("Number of stars: {}", _stars.len());
-        println!("Last Star: {:?}", _stars.last().unwrap());
+        println!("Number of rejected lines: {}", _stars.rejected.len());
+        println!("Last Star: {:?}", _stars.records.last().unwrap());
     }
 }
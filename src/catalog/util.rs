@@ -1,5 +1,17 @@
 //! Shared utilities for catalog parsing
 
+use super::Catalog;
+use crate::angle::{Angle, Sign};
+use crate::coord::ConstrainedAngle;
+use crate::parse::{CatalogReader, CatalogRecord, ParseError, ParseErrorKind};
+
+use std::ops::Range;
+use std::path::Path;
+
+// NOTE: the generic helpers moved to `crate::parse`, re-exported for the catalog parsers.
+pub use crate::parse::fields::{not_truncated, parse_field, parse_fixed, required, slice_field};
+pub use crate::parse::reader::decompress;
+
 /// Trim a string before parsing into a given type.
 #[macro_export]
 macro_rules! parse_trim {
@@ -18,50 +30,119 @@ macro_rules! parse_trim {
     };
 }
 
-/// Parse a catalog file into a vector of a given type
-#[macro_export]
-macro_rules! parse_catalog {
-    ($T:ty, $path:expr, $pad:expr) => {
-        {
-
-        use std::error::Error;
-        use std::fs::File;
-        use std::io::prelude::*;
-        use std::io::BufReader;
-        use std::path::Path;
-
-        let display = $path.display();
-
-        // Open the path in read-only mode, returns `io::Result<File>`
-        let file = match File::open(&$path) {
-            // The `description` method of `io::Error` returns a string that describes the error
-            Err(why) => panic!(
-                "couldn't open {}: {}",
-                display,
-                <dyn Error>::to_string(&why)
-            ),
-            Ok(file) => file,
-        };
-        let reader = BufReader::new(file);
-        let lines = reader.lines();
-        // lines is a instance of some type which implements Iterator<Item=&str>
-
-        let mut stars: Vec<$T> = [].to_vec();
-
-        for l in lines {
-            // NOTE: need to pad the line with empty space because it will terminate early with empty fields at the end.
-            let s = match $pad {
-                Some(n) => format!("{:<width$}", l.unwrap(), width = n),
-                None => l.unwrap(),
-            };
-            let result = <$T>::try_from(s);
-            match result {
-                Ok(star) => if star.is_valid_parse() { stars.push(star) },
-                Err(_) => ()
-            };
-            // println!("{:?}", star)
-        };
-        stars
+/**
+Parse a signed sexagesimal field (e.g. `"+01 05 20.4"` or `"-48_48_35.49"`) into its sign and parts, `None` if the field is blank.
+*/
+pub fn parse_sexagesimal(
+    text: &str,
+    separator: char,
+    columns: Range<usize>,
+    field: &'static str,
+) -> Result<Option<(Sign, u32, u32, f64)>, ParseError> {
+    let invalid = |reason: &str| {
+        ParseError::field(
+            columns.clone(),
+            field,
+            ParseErrorKind::Invalid {
+                text: text.trim().to_string(),
+                reason: reason.to_string(),
+            },
+        )
+    };
+
+    let trimmed = text.trim();
+    if trimmed.is_empty() {
+        return Ok(None);
     }
+    let (sign, unsigned) = match trimmed.as_bytes()[0] {
+        b'-' => (Sign::Negative, &trimmed[1..]),
+        b'+' => (Sign::Positive, &trimmed[1..]),
+        _ => (Sign::Positive, trimmed),
     };
+
+    let parts: Vec<&str> = unsigned.split(separator).collect();
+    match parts[..] {
+        [major, minor, second] => {
+            let major = parse_field::<u32>(major, columns.clone(), field)?;
+            let minor = parse_field::<u32>(minor, columns.clone(), field)?;
+            let second = parse_field::<f64>(second, columns.clone(), field)?;
+            match (major, minor, second) {
+                (Some(major), Some(minor), Some(second)) => Ok(Some((sign, major, minor, second))),
+                _ => Err(invalid("sexagesimal field has a blank part")),
+            }
+        }
+        _ => Err(invalid("expected three sexagesimal parts")),
+    }
+}
+
+/**
+Constrain an angle parsed from a field (e.g. a declination), reporting angles out of range as [`ParseErrorKind::Invalid`].
+*/
+pub fn constrained_field<T: ConstrainedAngle>(
+    angle: Angle,
+    text: &str,
+    columns: Range<usize>,
+    field: &'static str,
+) -> Result<T, ParseError> {
+    T::try_new(&angle).map_err(|why| {
+        ParseError::field(
+            columns,
+            field,
+            ParseErrorKind::Invalid {
+                text: text.trim().to_string(),
+                reason: why.to_string(),
+            },
+        )
+    })
+}
+
+/**
+Parse a catalog file into a [`Catalog`] of a given type.
+
+Compressed files are decompressed transparently (see [`decompress`]) and lines are padded to [`CatalogRecord::PAD`].
+Use a [`CatalogReader`] to override the padding.
+
+Lines that fail to parse (including truncated lines) are collected in [`Catalog::rejected`] with their line number.
+Records that fail [`crate::parse::ValidParse`] are filtered out, like [`CatalogReader`] does by default.
+Only failing to open or read the file is an error.
+*/
+pub fn parse_catalog<T: CatalogRecord>(path: impl AsRef<Path>) -> Result<Catalog<T>, ParseError> {
+    let mut catalog = Catalog {
+        records: vec![],
+        rejected: vec![],
+    };
+
+    for result in CatalogReader::<_, T>::open(path)? {
+        match result {
+            Ok(record) => catalog.records.push(record),
+            Err(ParseError::Io(why)) => return Err(ParseError::Io(why)),
+            Err(why) => catalog.rejected.push(why),
+        }
+    }
+
+    Ok(catalog)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::catalog::util::*;
+    use crate::catalog::yale::YaleStar;
+
+    #[test]
+    fn missing_catalog() {
+        match parse_catalog::<YaleStar>("data/does/not/exist.dat") {
+            Err(ParseError::Io(_)) => (),
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn sexagesimal() {
+        assert_eq!(
+            parse_sexagesimal("-48_48_35.49", '_', 0..12, "03").unwrap(),
+            Some((Sign::Negative, 48, 48, 35.49))
+        );
+        assert_eq!(parse_sexagesimal("   ", ' ', 0..3, "H3").unwrap(), None);
+        assert!(parse_sexagesimal("+01 05", ' ', 0..6, "H4").is_err());
+    }
 }
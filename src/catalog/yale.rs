@@ -158,11 +158,15 @@ Note on n_RadVel:
 - O = orbital data available.
 
 */
-use super::ValidParse;
-use crate::parse_trim;
+use crate::angle::{Angle, DegMinSec, HourMinSec, Sign};
+use crate::coord::{ConstrainedAngle, Declination, Equitorial, RightAscension};
+use crate::parse::fields::{not_truncated, parse_fixed, required};
+use crate::parse::{CatalogRecord, ParseError, ValidParse};
+
+use std::collections::HashMap;
 
 #[allow(non_snake_case)] // Copying field names from original data source
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct YaleStar {
     /// \[1/9110\]+ Harvard Revised Number = Bright Star Number
     pub HR: Option<usize>,
@@ -294,7 +298,7 @@ pub struct YaleStar {
     pub Parallax: Option<f64>,
 
     /// ? Heliocentric Radial Velocity
-    pub RadVel: Option<usize>,
+    pub RadVel: Option<isize>,
 
     /// \*\[V?SB123O \] Radial velocity comments
     pub n_RadVel: Option<String>,
@@ -325,69 +329,74 @@ pub struct YaleStar {
 }
 
 impl TryFrom<String> for YaleStar {
-    type Error = ();
+    type Error = ParseError;
 
     fn try_from(s: String) -> Result<Self, Self::Error> {
         let star = Self {
-            HR: parse_trim!(usize, s[0..4]),
-            Name: parse_trim!(String, s[4..14]),
-            DM: parse_trim!(String, s[14..25]),
-            HD: parse_trim!(usize, s[25..31]),
-            SAO: parse_trim!(usize, s[31..37]),
-            FK5: parse_trim!(usize, s[37..41]),
-            IRflag: parse_trim!(String, s[41..42]),
-            r_IRflag: parse_trim!(String, s[42..43]),
-            Multiple: parse_trim!(String, s[43..44]),
-            ADS: parse_trim!(String, s[44..49]),
-            ADScomp: parse_trim!(String, s[49..51]),
-            VarID: parse_trim!(String, s[51..60]),
-            RAh1900: parse_trim!(usize, s[60..62]),
-            RAm1900: parse_trim!(usize, s[62..64]),
-            RAs1900: parse_trim!(f64, s[64..68]),
-            DE_1900: parse_trim!(String, s[68..69]),
-            DEd1900: parse_trim!(usize, s[69..71]),
-            DEm1900: parse_trim!(usize, s[71..73]),
-            DEs1900: parse_trim!(usize, s[73..75]),
-            RAh: parse_trim!(usize, s[75..77]),
-            RAm: parse_trim!(usize, s[77..79]),
-            RAs: parse_trim!(f64, s[79..83]),
-            DE_: parse_trim!(String, s[83..84]),
-            DEd: parse_trim!(usize, s[84..86]),
-            DEm: parse_trim!(usize, s[86..88]),
-            DEs: parse_trim!(usize, s[88..90]),
-            GLON: parse_trim!(f64, s[90..96]),
-            GLAT: parse_trim!(f64, s[96..102]),
-            Vmag: parse_trim!(f64, s[102..107]),
-            n_Vmag: parse_trim!(String, s[107..108]),
-            u_Vmag: parse_trim!(String, s[108..109]),
-            B_V: parse_trim!(f64, s[109..114]),
-            u_B_V: parse_trim!(String, s[114..115]),
-            U_B: parse_trim!(f64, s[115..120]),
-            u_U_B: parse_trim!(String, s[120..121]),
-            R_I: parse_trim!(f64, s[121..126]),
-            n_R_I: parse_trim!(String, s[126..127]),
-            SpType: parse_trim!(String, s[127..147]),
-            n_SpType: parse_trim!(String, s[147..148]),
-            pmRA: parse_trim!(f64, s[148..154]),
-            pmDe: parse_trim!(f64, s[154..160]),
-            n_Parallax: parse_trim!(String, s[160..161]),
-            Parallax: parse_trim!(f64, s[161..166]),
-            RadVel: parse_trim!(usize, s[166..170]),
-            n_RadVel: parse_trim!(String, s[170..174]),
-            l_RotVel: parse_trim!(String, s[174..176]),
-            RotVel: parse_trim!(usize, s[176..179]),
-            u_RotVel: parse_trim!(String, s[179..180]),
-            Dmag: parse_trim!(f64, s[180..184]),
-            Sep: parse_trim!(f64, s[184..190]),
-            MultID: parse_trim!(String, s[190..194]),
-            MultCnt: parse_trim!(usize, s[194..196]),
-            NoteFlag: parse_trim!(String, s[196..197]),
+            HR: parse_fixed(&s, 0..4, "HR")?,
+            Name: parse_fixed(&s, 4..14, "Name")?,
+            DM: parse_fixed(&s, 14..25, "DM")?,
+            HD: parse_fixed(&s, 25..31, "HD")?,
+            SAO: parse_fixed(&s, 31..37, "SAO")?,
+            FK5: parse_fixed(&s, 37..41, "FK5")?,
+            IRflag: parse_fixed(&s, 41..42, "IRflag")?,
+            r_IRflag: parse_fixed(&s, 42..43, "r_IRflag")?,
+            Multiple: parse_fixed(&s, 43..44, "Multiple")?,
+            ADS: parse_fixed(&s, 44..49, "ADS")?,
+            ADScomp: parse_fixed(&s, 49..51, "ADScomp")?,
+            VarID: parse_fixed(&s, 51..60, "VarID")?,
+            RAh1900: parse_fixed(&s, 60..62, "RAh1900")?,
+            RAm1900: parse_fixed(&s, 62..64, "RAm1900")?,
+            RAs1900: parse_fixed(&s, 64..68, "RAs1900")?,
+            DE_1900: parse_fixed(&s, 68..69, "DE-1900")?,
+            DEd1900: parse_fixed(&s, 69..71, "DEd1900")?,
+            DEm1900: parse_fixed(&s, 71..73, "DEm1900")?,
+            DEs1900: parse_fixed(&s, 73..75, "DEs1900")?,
+            RAh: parse_fixed(&s, 75..77, "RAh")?,
+            RAm: parse_fixed(&s, 77..79, "RAm")?,
+            RAs: parse_fixed(&s, 79..83, "RAs")?,
+            DE_: parse_fixed(&s, 83..84, "DE-")?,
+            DEd: parse_fixed(&s, 84..86, "DEd")?,
+            DEm: parse_fixed(&s, 86..88, "DEm")?,
+            DEs: parse_fixed(&s, 88..90, "DEs")?,
+            GLON: parse_fixed(&s, 90..96, "GLON")?,
+            GLAT: parse_fixed(&s, 96..102, "GLAT")?,
+            Vmag: parse_fixed(&s, 102..107, "Vmag")?,
+            n_Vmag: parse_fixed(&s, 107..108, "n_Vmag")?,
+            u_Vmag: parse_fixed(&s, 108..109, "u_Vmag")?,
+            B_V: parse_fixed(&s, 109..114, "B-V")?,
+            u_B_V: parse_fixed(&s, 114..115, "u_B-V")?,
+            U_B: parse_fixed(&s, 115..120, "U-B")?,
+            u_U_B: parse_fixed(&s, 120..121, "u_U-B")?,
+            R_I: parse_fixed(&s, 121..126, "R-I")?,
+            n_R_I: parse_fixed(&s, 126..127, "n_R-I")?,
+            SpType: parse_fixed(&s, 127..147, "SpType")?,
+            n_SpType: parse_fixed(&s, 147..148, "n_SpType")?,
+            pmRA: parse_fixed(&s, 148..154, "pmRA")?,
+            pmDe: parse_fixed(&s, 154..160, "pmDE")?,
+            n_Parallax: parse_fixed(&s, 160..161, "n_Parallax")?,
+            Parallax: parse_fixed(&s, 161..166, "Parallax")?,
+            RadVel: parse_fixed(&s, 166..170, "RadVel")?,
+            n_RadVel: parse_fixed(&s, 170..174, "n_RadVel")?,
+            l_RotVel: parse_fixed(&s, 174..176, "l_RotVel")?,
+            RotVel: parse_fixed(&s, 176..179, "RotVel")?,
+            u_RotVel: parse_fixed(&s, 179..180, "u_RotVel")?,
+            Dmag: parse_fixed(&s, 180..184, "Dmag")?,
+            Sep: parse_fixed(&s, 184..190, "Sep")?,
+            MultID: parse_fixed(&s, 190..194, "MultID")?,
+            MultCnt: parse_fixed(&s, 194..196, "MultCnt")?,
+            NoteFlag: parse_fixed(&s, 196..197, "NoteFlag")?,
         };
-        if star.is_valid_parse() {
-            Ok(star)
-        } else {
-            Err(())
-        }
+        required(&s, &star.HR, 0..4, "HR")?;
+        // NOTE: positions are blank for objects removed from the catalog (see note 1), those fail `ValidParse` instead.
+        not_truncated(&s, 75..77, "RAh")?;
+        not_truncated(&s, 77..79, "RAm")?;
+        not_truncated(&s, 79..83, "RAs")?;
+        not_truncated(&s, 83..84, "DE-")?;
+        not_truncated(&s, 84..86, "DEd")?;
+        not_truncated(&s, 86..88, "DEm")?;
+        not_truncated(&s, 88..90, "DEs")?;
+        Ok(star)
     }
 }
 
@@ -404,28 +413,483 @@ impl ValidParse for YaleStar {
     }
 }
 
+impl CatalogRecord for YaleStar {
+    // NOTE: the catalog strips trailing spaces, so lines of removed objects end before the positions.
+    const PAD: Option<usize> = Some(197);
+}
+
+/// Assemble an equatorial position from the sexagesimal catalog fields.
+fn equatorial(
+    (hour, minute, second): (Option<usize>, Option<usize>, Option<f64>),
+    sign: &Option<String>,
+    (degree, arc_minute, arc_second): (Option<usize>, Option<usize>, Option<usize>),
+) -> Option<Equitorial> {
+    let sign = match sign.as_deref()? {
+        "+" => Sign::Positive,
+        "-" => Sign::Negative,
+        _ => return None,
+    };
+    Some(Equitorial {
+        right_ascension: RightAscension::try_new(&Angle::from(HourMinSec(
+            Sign::Positive,
+            hour? as u32,
+            minute? as u32,
+            second?,
+        )))
+        .ok()?,
+        // NOTE: the sign is a separate field so that declinations between 0 and -1 degree keep their sign.
+        declination: Declination::try_new(&Angle::from(DegMinSec(
+            sign,
+            degree? as u32,
+            arc_minute? as u32,
+            arc_second? as f64,
+        )))
+        .ok()?,
+    })
+}
+
+/// Split a name like `21Alp And` into the Flamsteed/Bayer prefix and the constellation abbreviation.
+fn split_name(name: &str) -> Option<(&str, &str)> {
+    // NOTE: the constellation is always the last three bytes (12-14) of the name field.
+    let name = name.trim();
+    let split = name.len().checked_sub(3)?;
+    if !name.is_char_boundary(split) {
+        return None;
+    }
+    let (prefix, constellation) = name.split_at(split);
+    Some((prefix, constellation))
+}
+
+impl YaleStar {
+    /// J2000 equatorial position (equinox J2000, epoch 2000.0), `None` if any field is blank or out of range.
+    pub fn equatorial_j2000(&self) -> Option<Equitorial> {
+        equatorial(
+            (self.RAh, self.RAm, self.RAs),
+            &self.DE_,
+            (self.DEd, self.DEm, self.DEs),
+        )
+    }
+
+    /// B1900 equatorial position (equinox B1900, epoch 1900.0), `None` if any field is blank or out of range.
+    pub fn equatorial_b1900(&self) -> Option<Equitorial> {
+        equatorial(
+            (self.RAh1900, self.RAm1900, self.RAs1900),
+            &self.DE_1900,
+            (self.DEd1900, self.DEm1900, self.DEs1900),
+        )
+    }
+
+    /// Flamsteed designation (e.g. `21 And`) from the name field, `None` if the star has none.
+    pub fn flamsteed(&self) -> Option<String> {
+        let (prefix, constellation) = split_name(self.Name.as_deref()?)?;
+        let number = prefix.trim_start();
+        let digits = number.len()
+            - number
+                .trim_start_matches(|c: char| c.is_ascii_digit())
+                .len();
+        match digits {
+            0 => None,
+            n => Some(format!("{} {}", &number[..n], constellation)),
+        }
+    }
+
+    /// Bayer designation (e.g. `Alp And`, `Kap1 Scl`) from the name field, `None` if the star has none.
+    pub fn bayer(&self) -> Option<String> {
+        let (prefix, constellation) = split_name(self.Name.as_deref()?)?;
+        match prefix
+            .trim_start_matches(|c: char| c.is_ascii_digit() || c == ' ')
+            .trim_end()
+        {
+            "" => None,
+            letter => Some(format!("{} {}", letter, constellation)),
+        }
+    }
+
+    /// Notes attached to this star (empty if the star has no HR number or no notes).
+    pub fn notes<'a>(&self, notes: &'a YaleNotes) -> &'a [YaleNote] {
+        match self.HR {
+            Some(hr) => notes.get(hr),
+            None => &[],
+        }
+    }
+}
+
+/**
+Remark category of a [`YaleNote`]
+
+From the notes ReadMe.
+*/
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum YaleNoteCategory {
+    /// `C:` Colors
+    Colors,
+    /// `D:` Double and multiple stars
+    Double,
+    /// `DYN:` Dynamical parallaxes
+    DynamicalParallax,
+    /// `G:` Group membership
+    Group,
+    /// `M:` Miscellaneous
+    Miscellaneous,
+    /// `N:` Star names
+    Name,
+    /// `P:` Polarization
+    Polarization,
+    /// `R:` Stellar radii or diameters
+    Radius,
+    /// `RV:` Radial and/or rotational velocities
+    Velocity,
+    /// `S:` Spectra
+    Spectra,
+    /// `SB:` Spectroscopic binaries
+    SpectroscopicBinary,
+    /// `VAR:` Variability
+    Variability,
+    /// Any other category code (without the trailing colon)
+    Other(String),
+}
+
+impl std::str::FromStr for YaleNoteCategory {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.trim().trim_end_matches(':') {
+            "C" => Self::Colors,
+            "D" => Self::Double,
+            "DYN" => Self::DynamicalParallax,
+            "G" => Self::Group,
+            "M" => Self::Miscellaneous,
+            "N" => Self::Name,
+            "P" => Self::Polarization,
+            "R" => Self::Radius,
+            "RV" => Self::Velocity,
+            "S" => Self::Spectra,
+            "SB" => Self::SpectroscopicBinary,
+            "VAR" => Self::Variability,
+            other => Self::Other(other.to_string()),
+        })
+    }
+}
+
+/**
+Yale Bright Star Catalog note (one line of `bsc5.notes`)
+
+## Byte-by-byte Description of file: notes
+
+```text
+--------------------------------------------------------------------------------
+   Bytes Format  Units   Label    Explanations
+--------------------------------------------------------------------------------
+   2-  5  I4     ---     HR       [1/9110]+= Harvard Revised (HR)
+   6-  7  I2     ---     Count    Note counter (sequential for a star)
+   8- 11  A4     ---     Category [A-Z: ] Remark category abbreviation
+  12-132  A121   ---     Remark   Remarks in free form text
+--------------------------------------------------------------------------------
+```
+
+A single remark often spans several lines, each with the same category and an increasing counter.
+*/
+#[allow(non_snake_case)] // Copying field names from original data source
+#[derive(Debug, Clone)]
+pub struct YaleNote {
+    /// \[1/9110\]+= Harvard Revised (HR)
+    pub HR: Option<usize>,
+
+    /// Note counter (sequential for a star)
+    pub Count: Option<usize>,
+
+    /// \[A-Z: \] Remark category abbreviation
+    pub Category: Option<YaleNoteCategory>,
+
+    /// Remarks in free form text
+    pub Remark: Option<String>,
+}
+
+impl TryFrom<String> for YaleNote {
+    type Error = ParseError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        let note = Self {
+            HR: parse_fixed(&s, 1..5, "HR")?,
+            Count: parse_fixed(&s, 5..7, "Count")?,
+            Category: parse_fixed(&s, 7..11, "Category")?,
+            Remark: parse_fixed(&s, 11..132, "Remark")?,
+        };
+        required(&s, &note.HR, 1..5, "HR")?;
+        Ok(note)
+    }
+}
+
+impl ValidParse for YaleNote {
+    fn is_valid_parse(&self) -> bool {
+        self.HR.is_some()
+    }
+}
+
+impl CatalogRecord for YaleNote {}
+
+/// Yale notes grouped by HR number, used to join notes onto [`YaleStar`] records.
+#[derive(Debug, Clone, Default)]
+pub struct YaleNotes(HashMap<usize, Vec<YaleNote>>);
+
+impl YaleNotes {
+    /// Notes for a given HR number, in catalog order.
+    pub fn get(&self, hr: usize) -> &[YaleNote] {
+        self.0.get(&hr).map_or(&[], Vec::as_slice)
+    }
+
+    /// Remarks of a given category for a given HR number.
+    pub fn remarks<'a>(
+        &'a self,
+        hr: usize,
+        category: &'a YaleNoteCategory,
+    ) -> impl Iterator<Item = &'a str> + 'a {
+        self.get(hr)
+            .iter()
+            .filter(move |note| note.Category.as_ref() == Some(category))
+            .filter_map(|note| note.Remark.as_deref())
+    }
+}
+
+impl FromIterator<YaleNote> for YaleNotes {
+    fn from_iter<I: IntoIterator<Item = YaleNote>>(iter: I) -> Self {
+        let mut notes = HashMap::<usize, Vec<YaleNote>>::new();
+        for note in iter {
+            if let Some(hr) = note.HR {
+                notes.entry(hr).or_default().push(note);
+            }
+        }
+        Self(notes)
+    }
+}
+
+/// HR 1, the first line of `fixtures/Yale/bsc5.dat`, shared by tests.
+#[cfg(test)]
+pub(crate) fn hr_1() -> String {
+    include_str!("../../fixtures/Yale/bsc5.dat")
+        .lines()
+        .next()
+        .unwrap()
+        .to_string()
+}
+
 #[cfg(test)]
 mod tests {
+    use crate::catalog::parse_catalog;
     use crate::catalog::yale::*;
-    use crate::parse_catalog;
+    use crate::catalog::ParseErrorKind;
+    use crate::coord::Galactic;
 
     #[test]
     fn yalestar_from() {
-        let s = String::from("   1          BD+44 4550      3 36042          46           000001.1+444022000509.9+451345114.44-16.88 6.70  +0.07 +0.08         A1Vn               -0.012-0.018      -018      195  4.2  21.6AC   3 ");
-        YaleStar::try_from(s).unwrap();
+        let s = hr_1();
+        let star = YaleStar::try_from(s).unwrap();
+        assert_eq!(star.HR.unwrap(), 1);
+        assert_eq!(star.RadVel.unwrap(), -18);
+    }
+
+    #[test]
+    fn yalestar_equatorial() {
+        let s = hr_1();
+        let star = YaleStar::try_from(s.clone()).unwrap();
+
+        let j2000 = star.equatorial_j2000().unwrap();
+        assert_float_absolute_eq!(
+            j2000.right_ascension.value().to_hr(),
+            5.0 / 60.0 + 9.9 / 3600.0
+        );
+        assert_float_absolute_eq!(
+            j2000.declination.value().to_deg(),
+            45.0 + 13.0 / 60.0 + 45.0 / 3600.0
+        );
+
+        let b1900 = star.equatorial_b1900().unwrap();
+        assert_float_absolute_eq!(b1900.right_ascension.value().to_hr(), 1.1 / 3600.0);
+        assert_float_absolute_eq!(
+            b1900.declination.value().to_deg(),
+            44.0 + 40.0 / 60.0 + 22.0 / 3600.0
+        );
+
+        // Negative declination with zero degrees
+        let mut south = s.clone();
+        south.replace_range(83..90, "-003011");
+        let south = YaleStar::try_from(south)
+            .unwrap()
+            .equatorial_j2000()
+            .unwrap();
+        assert_float_absolute_eq!(
+            south.declination.value().to_deg(),
+            -(30.0 / 60.0 + 11.0 / 3600.0)
+        );
+
+        // Blank fields
+        let mut removed = s;
+        removed.replace_range(60..75, &" ".repeat(15));
+        assert!(YaleStar::try_from(removed)
+            .unwrap()
+            .equatorial_b1900()
+            .is_none());
+    }
+
+    #[test]
+    fn yalestar_galactic() {
+        let s = hr_1();
+        // Sirius (HR 2491) is past 180 degrees of galactic longitude
+        let mut sirius = s.clone();
+        sirius.replace_range(75..102, "064508.9-164258227.23-08.89");
+
+        for s in [s, sirius] {
+            let star = YaleStar::try_from(s).unwrap();
+            let gal = Galactic::from_equitorial(&star.equatorial_j2000().unwrap());
+
+            // NOTE: the catalog rounds GLON/GLAT to 0.01 degree.
+            assert_float_absolute_eq!(gal.longitude.value().to_deg(), star.GLON.unwrap(), 0.01);
+            assert_float_absolute_eq!(gal.latitude.value().to_deg(), star.GLAT.unwrap(), 0.01);
+        }
+    }
+
+    #[test]
+    fn yalestar_errors() {
+        let s = hr_1();
+
+        // Malformed field
+        let mut malformed = s.clone();
+        malformed.replace_range(75..77, "0x");
+        match YaleStar::try_from(malformed) {
+            Err(ParseError::Field {
+                columns,
+                field,
+                kind: ParseErrorKind::Invalid { text, .. },
+                ..
+            }) => {
+                assert_eq!(columns, 75..77);
+                assert_eq!(field, "RAh");
+                assert_eq!(text, "0x");
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+
+        // Line cut off before the J2000 position
+        let truncated = YaleStar::try_from(s[..70].to_string()).unwrap_err();
+        assert_eq!(truncated.kind(), Some(&ParseErrorKind::Truncated));
+        assert_eq!(
+            truncated.at_line(92).to_string(),
+            "line 92, bytes 76-77 (RAh): line ends before field"
+        );
+
+        // Blank HR number
+        let mut unnumbered = s.clone();
+        unnumbered.replace_range(0..4, "    ");
+        let unnumbered = YaleStar::try_from(unnumbered).unwrap_err();
+        assert_eq!(unnumbered.kind(), Some(&ParseErrorKind::Missing));
+        assert_eq!(
+            unnumbered.at_line(92).to_string(),
+            "line 92, bytes 1-4 (HR): required field is blank"
+        );
+
+        // Object removed from the catalog (positions are blank) parses but fails `ValidParse`
+        let mut removed = s.clone();
+        removed.replace_range(60..102, &" ".repeat(42));
+        let removed = YaleStar::try_from(removed).unwrap();
+        assert!(!removed.is_valid_parse());
+        assert!(removed.equatorial_j2000().is_none());
     }
 
     #[test]
     #[ignore]
     fn catalog() {
-        let data_file = "data/Yale/bsc5.dat";
+        let data_file = "data/Yale/bsc5.dat.gz";
 
         if !std::path::Path::new(&data_file).exists() {
             panic!("File \"{}\" doesn't exist. Please run \"get_data.sh\" to fetch the data required for this test.", &data_file)
         };
 
-        let _stars = parse_catalog!(YaleStar, Path::new(&data_file), Some(197));
+        let _stars = parse_catalog::<YaleStar>(data_file).unwrap();
         println!("Number of stars: {}", _stars.len());
-        println!("Last Star: {:?}", _stars.last().unwrap());
+        assert!(_stars.rejected.is_empty(), "{:?}", _stars.rejected);
+        println!("Last Star: {:?}", _stars.records.last().unwrap());
+    }
+
+    #[test]
+    #[ignore]
+    fn catalog_galactic() {
+        let data_file = "data/Yale/bsc5.dat.gz";
+
+        if !std::path::Path::new(&data_file).exists() {
+            panic!("File \"{}\" doesn't exist. Please run \"get_data.sh\" to fetch the data required for this test.", &data_file)
+        };
+
+        for star in parse_catalog::<YaleStar>(data_file).unwrap().records {
+            let (Some(eq), Some(glon), Some(glat)) =
+                (star.equatorial_j2000(), star.GLON, star.GLAT)
+            else {
+                continue;
+            };
+            let gal = Galactic::from_equitorial(&eq);
+            // NOTE: longitude differences shrink towards the galactic poles and wrap at 360 degrees.
+            let dlon = ((gal.longitude.value().to_deg() - glon + 180.0).rem_euclid(360.0) - 180.0)
+                * glat.to_radians().cos();
+            assert_float_absolute_eq!(dlon, 0.0, 0.02);
+            assert_float_absolute_eq!(gal.latitude.value().to_deg(), glat, 0.02);
+        }
+    }
+
+    #[test]
+    fn yalenote_from() {
+        let note = YaleNote::try_from(String::from(
+            "    1 1N:  Example remark giving a proper name",
+        ))
+        .unwrap();
+        assert_eq!(note.HR, Some(1));
+        assert_eq!(note.Count, Some(1));
+        assert_eq!(note.Category, Some(YaleNoteCategory::Name));
+        assert_eq!(
+            note.Remark.as_deref(),
+            Some("Example remark giving a proper name")
+        );
+
+        let note = YaleNote::try_from(String::from("   12 3DYN:Example")).unwrap();
+        assert_eq!(note.Category, Some(YaleNoteCategory::DynamicalParallax));
+        assert_eq!(note.Remark.as_deref(), Some("Example"));
+    }
+
+    #[test]
+    fn join_notes() {
+        let star = YaleStar::try_from(hr_1()).unwrap();
+
+        let notes: YaleNotes = [
+            "    1 1N:  First name line",
+            "    1 2D:  Double star remark",
+            "    1 3N:  Second name line",
+            "    2 1M:  Note for another star",
+        ]
+        .into_iter()
+        .map(|line| YaleNote::try_from(line.to_string()).unwrap())
+        .collect();
+
+        assert_eq!(star.notes(&notes).len(), 3);
+        assert_eq!(
+            notes
+                .remarks(1, &YaleNoteCategory::Name)
+                .collect::<Vec<_>>(),
+            vec!["First name line", "Second name line"]
+        );
+        assert!(notes.get(3).is_empty());
+    }
+
+    #[test]
+    #[ignore]
+    fn notes() {
+        let data_file = "data/Yale/bsc5.notes.gz";
+
+        if !std::path::Path::new(&data_file).exists() {
+            panic!("File \"{}\" doesn't exist. Please run \"get_data.sh\" to fetch the data required for this test.", &data_file)
+        };
+
+        let _notes = parse_catalog::<YaleNote>(data_file).unwrap();
+        println!("Number of notes: {}", _notes.len());
+        println!("Number of rejected lines: {}", _notes.rejected.len());
+        let _notes: YaleNotes = _notes.records.into_iter().collect();
+        println!("Notes for HR 1: {:?}", _notes.get(1));
     }
 }
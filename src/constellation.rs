@@ -76,9 +76,8 @@ macro_rules! parse_constellation_catalog {
 mod tests {
     use std::collections::HashMap;
 
-    use crate::catalog::ValidParse;
+    use crate::catalog::CatalogReader;
     use crate::constellation::*;
-    use crate::parse_catalog;
 
     #[test]
     #[ignore]
@@ -89,17 +88,10 @@ mod tests {
             panic!("File \"{}\" doesn't exist. Please run \"get_data.sh\" to fetch the data required for this test.", &data_file)
         };
 
-        let _stars = parse_catalog!(
-            OSBSCStar,
-            Path::new(&data_file),
-            // NOTE: it seems like we don't need to pad this catalog even though it has no delimiters.
-            // In case it breaks in the future: Some(262)
-            None
-        );
-
         let mut _star_map = HashMap::new();
 
-        for star in _stars {
+        for star in CatalogReader::<_, OSBSCStar>::open(data_file).unwrap() {
+            let star = star.unwrap();
             _star_map.insert(star.Hipparcos_id.unwrap(), star);
         }
 
@@ -136,17 +128,10 @@ mod tests {
             panic!("File \"{}\" doesn't exist. Please run \"get_data.sh\" to fetch the data required for this test.", &data_file)
         };
 
-        let _stars = parse_catalog!(
-            OSBSCStar,
-            Path::new(&data_file),
-            // NOTE: it seems like we don't need to pad this catalog even though it has no delimiters.
-            // In case it breaks in the future: Some(262)
-            None
-        );
-
         let mut _star_map = HashMap::new();
 
-        for star in _stars {
+        for star in CatalogReader::<_, OSBSCStar>::open(data_file).unwrap() {
+            let star = star.unwrap();
             _star_map.insert(star.Hipparcos_id.unwrap(), star);
         }
 
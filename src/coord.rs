@@ -5,16 +5,95 @@ Coordinate Systems
  */
 
 use crate::angle::{Angle, PI, PI_FOURTH, PI_HALF};
-use crate::time::GMST;
+use crate::time::{JulianDate, SiderealTime};
+
+use auto_ops::*;
+
+use std::fmt;
+
+/// Error returned when an angle does not satisfy the constraints of a coordinate.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AngleRangeError {
+    /// Name of the coordinate (e.g. `Declination`)
+    pub coordinate: &'static str,
+    /// Allowed range
+    pub range: &'static str,
+    /// Rejected angle
+    pub angle: Angle,
+}
+
+impl fmt::Display for AngleRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} must be {}, got {} degrees",
+            self.coordinate,
+            self.range,
+            self.angle.to_deg()
+        )
+    }
+}
+
+impl std::error::Error for AngleRangeError {}
 
 /// Trait for constrained angles
-pub trait ConstrainedAngle {
+pub trait ConstrainedAngle: Sized {
+    /// Angle constructor that returns an error when the underlying angle does not satisfy constraints.
+    fn try_new(angle: &Angle) -> Result<Self, AngleRangeError>;
     /// Angle constructor that panics when the underlying angle does not satisfy constraints.
-    fn new(angle: &Angle) -> Self;
+    fn new(angle: &Angle) -> Self {
+        match Self::try_new(angle) {
+            Ok(constrained) => constrained,
+            Err(why) => panic!("{}", why),
+        }
+    }
     /// Get underlying angle
     fn value(&self) -> Angle;
 }
 
+/**
+Constrained angle implementation
+
+- `range` angles are rejected outside of a closed range of radians.
+- `wrap` angles are normalised into a full turn and only rejected when they are not finite.
+*/
+macro_rules! impl_constrained_angle {
+    (range $T:ident, $min:expr, $max:expr, $range:literal) => {
+        impl ConstrainedAngle for $T {
+            fn try_new(angle: &Angle) -> Result<Self, AngleRangeError> {
+                if !($min..=$max).contains(&angle.to_rad()) {
+                    return Err(AngleRangeError {
+                        coordinate: stringify!($T),
+                        range: $range,
+                        angle: *angle,
+                    });
+                }
+                Ok(Self(*angle))
+            }
+            fn value(&self) -> Angle {
+                self.0
+            }
+        }
+    };
+    (wrap $T:ident, $normalize:ident) => {
+        impl ConstrainedAngle for $T {
+            fn try_new(angle: &Angle) -> Result<Self, AngleRangeError> {
+                if !angle.to_rad().is_finite() {
+                    return Err(AngleRangeError {
+                        coordinate: stringify!($T),
+                        range: "finite",
+                        angle: *angle,
+                    });
+                }
+                Ok(Self(angle.$normalize()))
+            }
+            fn value(&self) -> Angle {
+                self.0
+            }
+        }
+    };
+}
+
 /**
 Zenith Angle
 
@@ -23,18 +102,7 @@ Zenith Angle
 #[derive(Debug, Copy, Clone)]
 pub struct ZenithAngle(Angle);
 
-impl ConstrainedAngle for ZenithAngle {
-    fn new(angle: &Angle) -> Self {
-        let rad = angle.to_rad();
-        if !(0.0..=PI).contains(&rad) {
-            panic!("ZenithAngle must be between 0 and pi!")
-        }
-        Self(*angle)
-    }
-    fn value(&self) -> Angle {
-        self.0
-    }
-}
+impl_constrained_angle!(range ZenithAngle, 0.0, PI, "between 0 and pi");
 
 /**
 Declination
@@ -42,20 +110,9 @@ Declination
 <https://en.wikipedia.org/wiki/Declination>
  */
 #[derive(Debug, Copy, Clone)]
-pub struct Declination(pub Angle);
+pub struct Declination(Angle);
 
-impl ConstrainedAngle for Declination {
-    fn new(angle: &Angle) -> Self {
-        let rad = angle.to_rad();
-        if !(-PI_HALF..=PI_HALF).contains(&rad) {
-            panic!("Declination must be between -pi/2 and pi/2!")
-        }
-        Self(*angle)
-    }
-    fn value(&self) -> Angle {
-        self.0
-    }
-}
+impl_constrained_angle!(range Declination, -PI_HALF, PI_HALF, "between -pi/2 and pi/2");
 
 /**
 Altitude
@@ -63,20 +120,9 @@ Altitude
 <https://en.wikipedia.org/wiki/Horizontal_coordinate_system>
  */
 #[derive(Debug, Copy, Clone)]
-pub struct Altitude(pub Angle);
+pub struct Altitude(Angle);
 
-impl ConstrainedAngle for Altitude {
-    fn new(angle: &Angle) -> Self {
-        let rad = angle.to_rad();
-        if !(-PI_HALF..=PI_HALF).contains(&rad) {
-            panic!("Altitude must be between -pi/2 and pi/2!")
-        }
-        Self(*angle)
-    }
-    fn value(&self) -> Angle {
-        self.0
-    }
-}
+impl_constrained_angle!(range Altitude, -PI_HALF, PI_HALF, "between -pi/2 and pi/2");
 
 /**
 Latitude
@@ -84,58 +130,169 @@ Latitude
 <https://en.wikipedia.org/wiki/Latitude>
  */
 #[derive(Debug, Copy, Clone)]
-pub struct Latitude(pub Angle);
+pub struct Latitude(Angle);
 
-impl ConstrainedAngle for Latitude {
-    fn new(angle: &Angle) -> Self {
-        let rad = angle.to_rad();
-        if !(-PI_HALF..=PI_HALF).contains(&rad) {
-            panic!("Latitude must be between -pi/2 and pi/2!")
-        }
-        Self(*angle)
-    }
-    fn value(&self) -> Angle {
-        self.0
-    }
-}
+impl_constrained_angle!(range Latitude, -PI_HALF, PI_HALF, "between -pi/2 and pi/2");
 
 /**
-Right Ascension
+Right Ascension, wrapped between 0 and 24 hours
 
 <https://en.wikipedia.org/wiki/Right_ascension>
  */
 #[derive(Debug, Copy, Clone)]
-pub struct RightAscension(pub Angle);
+pub struct RightAscension(Angle);
+
+impl_constrained_angle!(wrap RightAscension, normalize_0_24h);
 
 /**
-Azimuth
+Azimuth, wrapped between 0 and 360 degrees
 
 <https://en.wikipedia.org/wiki/Azimuth>
  */
 #[derive(Debug, Copy, Clone)]
-pub struct Azimuth(pub Angle);
+pub struct Azimuth(Angle);
+
+impl_constrained_angle!(wrap Azimuth, normalize_0_360);
 
 /**
-Longitude
+Longitude, wrapped between -180 and 180 degrees
 
 <https://en.wikipedia.org/wiki/Longitude>
  */
 #[derive(Debug, Copy, Clone)]
-pub struct Longitude(pub Angle);
+pub struct Longitude(Angle);
+
+impl_constrained_angle!(wrap Longitude, normalize_pm180);
+
+/**
+Celestial longitude (galactic or ecliptic), wrapped between 0 and 360 degrees
+
+<https://en.wikipedia.org/wiki/Galactic_coordinate_system>
+<https://en.wikipedia.org/wiki/Ecliptic_coordinate_system>
+ */
+#[derive(Debug, Copy, Clone)]
+pub struct CelestialLongitude(Angle);
+
+impl_constrained_angle!(wrap CelestialLongitude, normalize_0_360);
 
 /**
 Cartesian Coordinates
 
 <https://en.wikipedia.org/wiki/Cartesian_coordinate_system>
  */
-#[derive(Debug, Copy, Clone)]
-#[allow(dead_code)]
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
 pub struct Cartesian {
     pub x: f64,
     pub y: f64,
     pub z: f64,
 }
 
+impl Cartesian {
+    /// Dot product
+    pub fn dot(&self, other: &Cartesian) -> f64 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// Cross product
+    pub fn cross(&self, other: &Cartesian) -> Self {
+        Self {
+            x: self.y * other.z - self.z * other.y,
+            y: self.z * other.x - self.x * other.z,
+            z: self.x * other.y - self.y * other.x,
+        }
+    }
+
+    /// Euclidean length
+    pub fn norm(&self) -> f64 {
+        self.dot(self).sqrt()
+    }
+
+    /// Unit vector in the same direction (components are NaN for the zero vector).
+    pub fn normalize(&self) -> Self {
+        self / self.norm()
+    }
+}
+
+impl_op_ex!(+|a: &Cartesian, b: &Cartesian| -> Cartesian {
+    Cartesian {
+        x: a.x + b.x,
+        y: a.y + b.y,
+        z: a.z + b.z,
+    }
+});
+impl_op_ex!(-|a: &Cartesian, b: &Cartesian| -> Cartesian {
+    Cartesian {
+        x: a.x - b.x,
+        y: a.y - b.y,
+        z: a.z - b.z,
+    }
+});
+impl_op_ex_commutative!(*|a: &Cartesian, b: &f64| -> Cartesian {
+    Cartesian {
+        x: a.x * b,
+        y: a.y * b,
+        z: a.z * b,
+    }
+});
+impl_op_ex!(/|a: &Cartesian, b: &f64| -> Cartesian {
+    Cartesian {
+        x: a.x / b,
+        y: a.y / b,
+        z: a.z / b,
+    }
+});
+
+/**
+3x3 matrix (row major) for rotating [`Cartesian`] vectors between frames.
+
+Rotations follow the SOFA convention: they rotate the reference frame, so [`Matrix3::rotation_z`] by +90 degrees maps the x axis onto -y.
+ */
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Matrix3(pub [[f64; 3]; 3]);
+
+impl Matrix3 {
+    /// Identity matrix
+    pub const IDENTITY: Self = Self([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]);
+
+    /// Frame rotation about the x axis
+    pub fn rotation_x(angle: &Angle) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self([[1.0, 0.0, 0.0], [0.0, cos, sin], [0.0, -sin, cos]])
+    }
+
+    /// Frame rotation about the y axis
+    pub fn rotation_y(angle: &Angle) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self([[cos, 0.0, -sin], [0.0, 1.0, 0.0], [sin, 0.0, cos]])
+    }
+
+    /// Frame rotation about the z axis
+    pub fn rotation_z(angle: &Angle) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self([[cos, sin, 0.0], [-sin, cos, 0.0], [0.0, 0.0, 1.0]])
+    }
+
+    /// Transpose, which is the inverse of a rotation
+    pub fn transpose(&self) -> Self {
+        let m = &self.0;
+        Self(std::array::from_fn(|i| std::array::from_fn(|j| m[j][i])))
+    }
+
+    /// Apply to a vector
+    pub fn apply(&self, v: &Cartesian) -> Cartesian {
+        let [x, y, z] = self.0.map(|row| row[0] * v.x + row[1] * v.y + row[2] * v.z);
+        Cartesian { x, y, z }
+    }
+}
+
+// NOTE: `a * b` applies `b` first, then `a`.
+impl_op_ex!(*|a: &Matrix3, b: &Matrix3| -> Matrix3 {
+    Matrix3(std::array::from_fn(|i| {
+        std::array::from_fn(|j| (0..3).map(|k| a.0[i][k] * b.0[k][j]).sum())
+    }))
+});
+impl_op_ex!(*|a: &Matrix3, v: &Cartesian| -> Cartesian { a.apply(v) });
+
 /**
 Geographic Coordinates (Latitude/Longitude)
 
@@ -181,26 +338,54 @@ pub struct Horizontal {
     pub azimuth: Azimuth,
 }
 
+/**
+Galactic Coordinates
+
+<https://en.wikipedia.org/wiki/Galactic_coordinate_system>
+ */
+#[derive(Debug, Copy, Clone)]
+pub struct Galactic {
+    pub longitude: CelestialLongitude,
+    pub latitude: Latitude,
+}
+
+/**
+Ecliptic Coordinates
+
+<https://en.wikipedia.org/wiki/Ecliptic_coordinate_system>
+ */
+#[derive(Debug, Copy, Clone)]
+pub struct Ecliptic {
+    pub longitude: CelestialLongitude,
+    pub latitude: Latitude,
+}
+
 impl Horizontal {
     /**
     Convert equitorial coordinates to horizontal given a place and time.
 
+    Azimuth is measured from north through east, between 0 and 2π.
+
     <https://en.wikipedia.org/wiki/Astronomical_coordinate_systems#Equatorial_%E2%86%94_horizontal>
      */
-    pub fn from_equitorial(eq: &Equitorial, geo: &Geographic, sidereal_time: &GMST) -> Self {
-        let hour_local: Angle = sidereal_time.0 + geo.longitude.0 - eq.right_ascension.0;
-        let x_horiz: f64 = -(geo.latitude.0.sin()) * (eq.declination.0.cos()) * (hour_local.cos())
-            + geo.latitude.0.cos() * (eq.declination.0.sin());
-        let y_horiz: f64 = eq.declination.0.cos() * hour_local.sin();
-        let azimuth_rad: Angle = Angle::Radian(-(y_horiz.atan2(x_horiz)));
-        let altitude_rad: Angle = Angle::Radian(
-            (geo.latitude.0.sin() * eq.declination.0.sin()
-                + geo.latitude.0.cos() * eq.declination.0.cos() * hour_local.cos())
-            .asin(),
+    pub fn from_equitorial(
+        eq: &Equitorial,
+        geo: &Geographic,
+        sidereal_time: &impl SiderealTime,
+    ) -> Self {
+        let hour_local: Angle = eq.hour_angle(geo, sidereal_time);
+        let x_horiz: f64 =
+            -(geo.latitude.value().sin()) * (eq.declination.value().cos()) * (hour_local.cos())
+                + geo.latitude.value().cos() * (eq.declination.value().sin());
+        let y_horiz: f64 = eq.declination.value().cos() * hour_local.sin();
+        let azimuth_rad: Angle = (-Angle::atan2(y_horiz, x_horiz)).normalize_0_360();
+        let altitude_rad: Angle = Angle::asin(
+            geo.latitude.value().sin() * eq.declination.value().sin()
+                + geo.latitude.value().cos() * eq.declination.value().cos() * hour_local.cos(),
         );
         Self {
-            altitude: Altitude(azimuth_rad),
-            azimuth: Azimuth(altitude_rad),
+            altitude: Altitude(altitude_rad),
+            azimuth: Azimuth(azimuth_rad),
         }
     }
 
@@ -211,8 +396,724 @@ impl Horizontal {
      */
     pub fn stereo_project(&self) -> Polar {
         Polar {
-            radius: 2.0 * (PI_FOURTH - self.altitude.0.to_rad() / 2.0).tan(),
-            angle: self.azimuth.0,
+            radius: 2.0 * (PI_FOURTH - self.altitude.value().to_rad() / 2.0).tan(),
+            angle: self.azimuth.value(),
+        }
+    }
+}
+
+impl Equitorial {
+    /**
+    Local hour angle given a place and time.
+
+    Positive west of the meridian, i.e. the time since the coordinates crossed the meridian.
+
+    <https://en.wikipedia.org/wiki/Hour_angle>
+     */
+    pub fn hour_angle(&self, geo: &Geographic, sidereal_time: &impl SiderealTime) -> Angle {
+        sidereal_time.at_longitude(&geo.longitude) - self.right_ascension.value()
+    }
+
+    /**
+    Convert horizontal coordinates to equitorial given a place and time.
+
+    Azimuth is measured from north through east.
+
+    <https://en.wikipedia.org/wiki/Astronomical_coordinate_systems#Equatorial_%E2%86%94_horizontal>
+     */
+    pub fn from_horizontal(
+        horiz: &Horizontal,
+        geo: &Geographic,
+        sidereal_time: &impl SiderealTime,
+    ) -> Self {
+        let latitude = geo.latitude.value();
+        let altitude = horiz.altitude.value();
+        let azimuth = horiz.azimuth.value();
+
+        let declination = Angle::asin(
+            latitude.sin() * altitude.sin() + latitude.cos() * altitude.cos() * azimuth.cos(),
+        );
+        let hour_local = Angle::atan2(
+            -azimuth.sin() * altitude.cos(),
+            latitude.cos() * altitude.sin() - latitude.sin() * altitude.cos() * azimuth.cos(),
+        );
+        let right_ascension = sidereal_time.at_longitude(&geo.longitude) - hour_local;
+
+        Self {
+            right_ascension: RightAscension(right_ascension.normalize_0_24h()),
+            declination: Declination(declination),
+        }
+    }
+}
+
+/// Right ascension of the north galactic pole (ICRS)
+pub(crate) const NGP_RIGHT_ASCENSION: f64 = 192.85948;
+/// Declination of the north galactic pole (ICRS)
+pub(crate) const NGP_DECLINATION: f64 = 27.12825;
+/// Galactic longitude of the north celestial pole (ICRS)
+pub(crate) const NCP_LONGITUDE: f64 = 122.93192;
+
+impl Galactic {
+    /**
+    Convert J2000 (ICRS) equitorial coordinates to galactic.
+
+    Uses the IAU 1958 galactic pole and origin expressed in ICRS.
+
+    <https://en.wikipedia.org/wiki/Astronomical_coordinate_systems#Equatorial_%E2%86%94_galactic>
+     */
+    pub fn from_equitorial(eq: &Equitorial) -> Self {
+        let pole = Angle::Degree(NGP_DECLINATION);
+        let hour = eq.right_ascension.value() - Angle::Degree(NGP_RIGHT_ASCENSION);
+        let dec = eq.declination.value();
+
+        let latitude = Angle::asin(dec.sin() * pole.sin() + dec.cos() * pole.cos() * hour.cos());
+        let longitude = Angle::Degree(NCP_LONGITUDE)
+            - Angle::atan2(
+                dec.cos() * hour.sin(),
+                dec.sin() * pole.cos() - dec.cos() * pole.sin() * hour.cos(),
+            );
+
+        Self {
+            longitude: CelestialLongitude(longitude.normalize_0_360()),
+            latitude: Latitude(latitude),
+        }
+    }
+}
+
+impl Equitorial {
+    /**
+    Convert galactic coordinates to J2000 (ICRS) equitorial.
+
+    <https://en.wikipedia.org/wiki/Astronomical_coordinate_systems#Equatorial_%E2%86%94_galactic>
+     */
+    pub fn from_galactic(gal: &Galactic) -> Self {
+        let pole = Angle::Degree(NGP_DECLINATION);
+        let longitude = Angle::Degree(NCP_LONGITUDE) - gal.longitude.value();
+        let lat = gal.latitude.value();
+
+        let declination =
+            Angle::asin(lat.sin() * pole.sin() + lat.cos() * pole.cos() * longitude.cos());
+        let right_ascension = Angle::Degree(NGP_RIGHT_ASCENSION)
+            + Angle::atan2(
+                lat.cos() * longitude.sin(),
+                lat.sin() * pole.cos() - lat.cos() * pole.sin() * longitude.cos(),
+            );
+
+        Self {
+            right_ascension: RightAscension(right_ascension.normalize_0_24h()),
+            declination: Declination(declination),
+        }
+    }
+}
+
+/// Model of the mean obliquity of the ecliptic
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ObliquityModel {
+    /// IAU 1980 (Lieske et al. 1977)
+    Iau1980,
+    /// IAU 2006 (Capitaine et al. 2003)
+    Iau2006,
+}
+
+/**
+Mean obliquity of the ecliptic at a (TT) julian date.
+
+<https://en.wikipedia.org/wiki/Axial_tilt#Short_term>
+ */
+pub fn mean_obliquity(julian_date: &JulianDate, model: ObliquityModel) -> Angle {
+    // Julian centuries since J2000
+    let t = julian_date.julian_centuries();
+    let arcsec = match model {
+        ObliquityModel::Iau1980 => 84381.448 + t * (-46.8150 + t * (-0.00059 + t * 0.001813)),
+        ObliquityModel::Iau2006 => {
+            84381.406
+                + t * (-46.836769
+                    + t * (-0.0001831 + t * (0.00200340 + t * (-0.000000576 + t * -0.0000000434))))
+        }
+    };
+    Angle::Degree(arcsec / 3600.0)
+}
+
+impl Ecliptic {
+    /**
+    Convert equitorial coordinates to ecliptic given the obliquity of the ecliptic (see [`mean_obliquity`]).
+
+    <https://en.wikipedia.org/wiki/Astronomical_coordinate_systems#Equatorial_%E2%86%94_ecliptic>
+     */
+    pub fn from_equitorial(eq: &Equitorial, obliquity: &Angle) -> Self {
+        let ra = eq.right_ascension.value();
+        let dec = eq.declination.value();
+
+        let longitude = Angle::atan2(
+            ra.sin() * obliquity.cos() + dec.tan() * obliquity.sin(),
+            ra.cos(),
+        );
+        let latitude =
+            Angle::asin(dec.sin() * obliquity.cos() - dec.cos() * obliquity.sin() * ra.sin());
+
+        Self {
+            longitude: CelestialLongitude(longitude.normalize_0_360()),
+            latitude: Latitude(latitude),
+        }
+    }
+}
+
+impl Equitorial {
+    /**
+    Convert ecliptic coordinates to equitorial given the obliquity of the ecliptic (see [`mean_obliquity`]).
+
+    <https://en.wikipedia.org/wiki/Astronomical_coordinate_systems#Equatorial_%E2%86%94_ecliptic>
+     */
+    pub fn from_ecliptic(ecl: &Ecliptic, obliquity: &Angle) -> Self {
+        let lon = ecl.longitude.value();
+        let lat = ecl.latitude.value();
+
+        let right_ascension = Angle::atan2(
+            lon.sin() * obliquity.cos() - lat.tan() * obliquity.sin(),
+            lon.cos(),
+        );
+        let declination =
+            Angle::asin(lat.sin() * obliquity.cos() + lat.cos() * obliquity.sin() * lon.sin());
+
+        Self {
+            right_ascension: RightAscension(right_ascension.normalize_0_24h()),
+            declination: Declination(declination),
+        }
+    }
+}
+
+impl Equitorial {
+    /**
+    Angular separation between two positions.
+
+    Uses the Vincenty formula, which is accurate for both tiny and antipodal separations.
+
+    <https://en.wikipedia.org/wiki/Great-circle_distance#Formulae>
+     */
+    pub fn separation(&self, other: &Equitorial) -> Angle {
+        let (sin_dec1, cos_dec1) = self.declination.value().sin_cos();
+        let (sin_dec2, cos_dec2) = other.declination.value().sin_cos();
+        let (sin_dra, cos_dra) =
+            (other.right_ascension.value() - self.right_ascension.value()).sin_cos();
+
+        let y = (cos_dec2 * sin_dra).hypot(cos_dec1 * sin_dec2 - sin_dec1 * cos_dec2 * cos_dra);
+        let x = sin_dec1 * sin_dec2 + cos_dec1 * cos_dec2 * cos_dra;
+        Angle::atan2(y, x)
+    }
+
+    /**
+    Position angle of another position, measured from north through east between 0 and 360 degrees.
+
+    <https://en.wikipedia.org/wiki/Position_angle>
+     */
+    pub fn position_angle(&self, other: &Equitorial) -> Angle {
+        let (sin_dec1, cos_dec1) = self.declination.value().sin_cos();
+        let (sin_dec2, cos_dec2) = other.declination.value().sin_cos();
+        let (sin_dra, cos_dra) =
+            (other.right_ascension.value() - self.right_ascension.value()).sin_cos();
+
+        Angle::atan2(
+            sin_dra * cos_dec2,
+            cos_dec1 * sin_dec2 - sin_dec1 * cos_dec2 * cos_dra,
+        )
+        .normalize_0_360()
+    }
+
+    /**
+    Position at a given position angle (north through east) and separation, e.g. a double star companion from its `theta` and `rho`.
+
+    Inverse of [`Equitorial::position_angle`] and [`Equitorial::separation`].
+     */
+    pub fn offset_by(&self, position_angle: &Angle, separation: &Angle) -> Self {
+        let (sin_dec, cos_dec) = self.declination.value().sin_cos();
+        let (sin_sep, cos_sep) = separation.sin_cos();
+        let (sin_pa, cos_pa) = position_angle.sin_cos();
+
+        let declination = Angle::asin(sin_dec * cos_sep + cos_dec * sin_sep * cos_pa);
+        let right_ascension = self.right_ascension.value()
+            + Angle::atan2(
+                sin_pa * sin_sep * cos_dec,
+                cos_sep - sin_dec * declination.sin(),
+            );
+
+        Self {
+            right_ascension: RightAscension(right_ascension.normalize_0_24h()),
+            declination: Declination(declination),
+        }
+    }
+}
+
+/// Unit vector pointing at the position (x towards the equinox, z towards the north pole).
+impl From<Equitorial> for Cartesian {
+    fn from(eq: Equitorial) -> Self {
+        let (sin_ra, cos_ra) = eq.right_ascension.value().sin_cos();
+        let (sin_dec, cos_dec) = eq.declination.value().sin_cos();
+        Self {
+            x: cos_dec * cos_ra,
+            y: cos_dec * sin_ra,
+            z: sin_dec,
         }
     }
 }
+
+/// Direction of a vector of any (non-zero) length.
+impl From<Cartesian> for Equitorial {
+    fn from(v: Cartesian) -> Self {
+        Self {
+            right_ascension: RightAscension(Angle::atan2(v.y, v.x).normalize_0_24h()),
+            // NOTE: atan2 rather than asin of the normalised z keeps precision near the poles.
+            declination: Declination(Angle::atan2(v.z, v.x.hypot(v.y))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::angle::{DegMinSec, HourMinSec, Sign};
+    use crate::coord::*;
+    use crate::time::GMST;
+
+    #[test]
+    fn constrained_angles() {
+        assert!(Declination::try_new(&Angle::Degree(45.0)).is_ok());
+        assert!(ZenithAngle::try_new(&Angle::Degree(-1.0)).is_err());
+        assert!(Altitude::try_new(&Angle::Degree(f64::NAN)).is_err());
+
+        let why = Latitude::try_new(&Angle::Degree(91.0)).unwrap_err();
+        assert_eq!(why.coordinate, "Latitude");
+        assert_eq!(
+            why.to_string(),
+            format!(
+                "Latitude must be between -pi/2 and pi/2, got {} degrees",
+                Angle::Degree(91.0).to_deg()
+            )
+        );
+
+        // Unconstrained coordinates wrap instead
+        assert_float_absolute_eq!(
+            RightAscension::new(&Angle::Hour(-1.0)).value().to_hr(),
+            23.0
+        );
+        assert_float_absolute_eq!(Azimuth::new(&Angle::Degree(370.0)).value().to_deg(), 10.0);
+        assert_float_absolute_eq!(
+            Longitude::new(&Angle::Degree(270.0)).value().to_deg(),
+            -90.0
+        );
+        assert!(Longitude::try_new(&Angle::Degree(f64::INFINITY)).is_err());
+        assert_float_absolute_eq!(
+            CelestialLongitude::new(&Angle::Degree(-90.0))
+                .value()
+                .to_deg(),
+            270.0
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Declination must be between -pi/2 and pi/2")]
+    fn constrained_angle_panics() {
+        Declination::new(&Angle::Degree(-90.5));
+    }
+
+    #[test]
+    fn galactic() {
+        // Galactic center
+        let center = Equitorial::from_galactic(&Galactic {
+            longitude: CelestialLongitude::new(&Angle::Degree(0.0)),
+            latitude: Latitude::new(&Angle::Degree(0.0)),
+        });
+        assert_float_absolute_eq!(center.right_ascension.value().to_deg(), 266.40499, 1e-4);
+        assert_float_absolute_eq!(center.declination.value().to_deg(), -28.93617, 1e-4);
+
+        // North galactic pole
+        let pole = Galactic::from_equitorial(&Equitorial {
+            right_ascension: RightAscension::new(&Angle::Degree(NGP_RIGHT_ASCENSION)),
+            declination: Declination::new(&Angle::Degree(NGP_DECLINATION)),
+        });
+        assert_float_absolute_eq!(pole.latitude.value().to_deg(), 90.0, 1e-6);
+
+        // Sirius, longitudes past 180 degrees don't wrap to negative
+        let sirius = Galactic::from_equitorial(&Equitorial {
+            right_ascension: RightAscension::new(&Angle::Degree(101.2875)),
+            declination: Declination::new(&Angle::Degree(-16.7161)),
+        });
+        assert_float_absolute_eq!(sirius.longitude.value().to_deg(), 227.23, 0.01);
+
+        // Round trip
+        for (ra, dec) in [
+            (0.0, 0.0),
+            (83.6331, 22.0145),
+            (279.2347, 38.7837),
+            (101.2875, -16.7161),
+        ] {
+            let eq = Equitorial {
+                right_ascension: RightAscension::new(&Angle::Degree(ra)),
+                declination: Declination::new(&Angle::Degree(dec)),
+            };
+            let back = Equitorial::from_galactic(&Galactic::from_equitorial(&eq));
+            assert_float_absolute_eq!(back.right_ascension.value().to_deg(), ra, 1e-9);
+            assert_float_absolute_eq!(back.declination.value().to_deg(), dec, 1e-9);
+        }
+    }
+
+    #[test]
+    fn horizontal_round_trip() {
+        let sidereal_time = GMST(Angle::Hour(7.25));
+        for (lat, lon) in [(52.5, -1.9167), (-33.9, 18.4), (0.0, 0.0), (78.2, 15.6)] {
+            let geo = Geographic {
+                latitude: Latitude::new(&Angle::Degree(lat)),
+                longitude: Longitude::new(&Angle::Degree(lon)),
+            };
+            for (ra, dec) in [
+                (250.425, 36.467),
+                (101.2875, -16.7161),
+                (37.9546, 89.2641),
+                (5.0, 0.0),
+            ] {
+                let eq = Equitorial {
+                    right_ascension: RightAscension::new(&Angle::Degree(ra)),
+                    declination: Declination::new(&Angle::Degree(dec)),
+                };
+                let horiz = Horizontal::from_equitorial(&eq, &geo, &sidereal_time);
+                let back = Equitorial::from_horizontal(&horiz, &geo, &sidereal_time);
+                assert_float_absolute_eq!(back.right_ascension.value().to_deg(), ra, 1e-8);
+                assert_float_absolute_eq!(back.declination.value().to_deg(), dec, 1e-8);
+            }
+        }
+    }
+
+    #[test]
+    fn hour_angle() {
+        // A star on the meridian has zero hour angle
+        let geo = Geographic {
+            latitude: Latitude::new(&Angle::Degree(40.0)),
+            longitude: Longitude::new(&Angle::Degree(-75.0)),
+        };
+        let eq = Equitorial {
+            right_ascension: RightAscension::new(&Angle::Hour(1.0)),
+            declination: Declination::new(&Angle::Degree(40.0)),
+        };
+        assert_float_absolute_eq!(eq.hour_angle(&geo, &GMST(Angle::Hour(6.0))).to_hr(), 0.0);
+        assert_float_absolute_eq!(eq.hour_angle(&geo, &GMST(Angle::Hour(8.0))).to_hr(), 2.0);
+
+        // ... and culminates at the zenith when its declination equals the latitude
+        let horiz = Horizontal::from_equitorial(&eq, &geo, &GMST(Angle::Hour(6.0)));
+        assert_float_absolute_eq!(horiz.altitude.value().to_deg(), 90.0, 1e-6);
+    }
+
+    fn site(latitude: f64, longitude: f64) -> Geographic {
+        Geographic {
+            latitude: Latitude::new(&Angle::Degree(latitude)),
+            longitude: Longitude::new(&Angle::Degree(longitude)),
+        }
+    }
+
+    fn equitorial(right_ascension: Angle, declination: Angle) -> Equitorial {
+        Equitorial {
+            right_ascension: RightAscension::new(&right_ascension),
+            declination: Declination::new(&declination),
+        }
+    }
+
+    #[test]
+    fn horizontal_reference() {
+        // Meeus, Astronomical Algorithms, Example 13.b: Venus from the U.S. Naval Observatory on 1987 April 10, 19:21 UT
+        // NOTE: Meeus measures azimuth from the south, so his 68.0337 degrees is 248.0337 degrees from north.
+        let washington = site(
+            Angle::from(DegMinSec(Sign::Positive, 38, 55, 17.0)).to_deg(),
+            Angle::from(DegMinSec(Sign::Negative, 77, 3, 56.0)).to_deg(),
+        );
+        let venus = equitorial(
+            Angle::from(HourMinSec(Sign::Positive, 23, 9, 16.641)),
+            Angle::from(DegMinSec(Sign::Negative, 6, 43, 11.61)),
+        );
+        let sidereal_time = GMST(Angle::from(HourMinSec(Sign::Positive, 8, 34, 57.0896)));
+        let horiz = Horizontal::from_equitorial(&venus, &washington, &sidereal_time);
+        assert_float_absolute_eq!(horiz.azimuth.value().to_deg(), 248.0337, 1e-3);
+        assert_float_absolute_eq!(horiz.altitude.value().to_deg(), 15.1249, 1e-3);
+
+        // Keith Burnett, "Converting RA and DEC to ALT and AZ": M13 from Birmingham UK on 1998 August 10, 23:10 UT
+        // <http://www.stargazing.net/kepler/altaz.html>
+        let birmingham = site(52.5, -(1.0 + 55.0 / 60.0));
+        let m13 = equitorial(
+            Angle::Hour(16.0 + 41.7 / 60.0),
+            Angle::Degree(36.0 + 28.0 / 60.0),
+        );
+        let sidereal_time = GMST(Angle::Degree(304.80762 + 1.0 + 55.0 / 60.0));
+        assert_float_absolute_eq!(
+            m13.hour_angle(&birmingham, &sidereal_time).to_deg(),
+            54.382617,
+            1e-5
+        );
+        let horiz = Horizontal::from_equitorial(&m13, &birmingham, &sidereal_time);
+        assert_float_absolute_eq!(horiz.azimuth.value().to_deg(), 269.14634, 1e-4);
+        assert_float_absolute_eq!(horiz.altitude.value().to_deg(), 49.169122, 1e-4);
+    }
+
+    #[test]
+    fn horizontal_meridian() {
+        // J2000 positions of Polaris, Vega and Sirius
+        let polaris = equitorial(Angle::Degree(37.95456), Angle::Degree(89.26411));
+        let vega = equitorial(Angle::Degree(279.23473), Angle::Degree(38.78369));
+        let sirius = equitorial(Angle::Degree(101.28716), Angle::Degree(-16.71612));
+
+        let geo = site(52.5, 13.4);
+        let transit = |eq: &Equitorial| {
+            GMST(Angle::Degree(
+                eq.right_ascension.value().to_deg() - geo.longitude.value().to_deg(),
+            ))
+        };
+
+        // Stars culminate due south at 90 - latitude + declination
+        for (star, altitude) in [
+            (&vega, 90.0 - 52.5 + 38.78369),
+            (&sirius, 90.0 - 52.5 - 16.71612),
+        ] {
+            let horiz = Horizontal::from_equitorial(star, &geo, &transit(star));
+            assert_float_absolute_eq!(horiz.azimuth.value().to_deg(), 180.0, 1e-9);
+            assert_float_absolute_eq!(horiz.altitude.value().to_deg(), altitude, 1e-9);
+        }
+
+        // Polaris culminates due north just above the latitude and stays within a degree of it all night
+        let horiz = Horizontal::from_equitorial(&polaris, &geo, &transit(&polaris));
+        assert_float_absolute_eq!(horiz.azimuth.value().to_deg(), 0.0, 1e-9);
+        assert_float_absolute_eq!(
+            horiz.altitude.value().to_deg(),
+            52.5 + 90.0 - 89.26411,
+            1e-9
+        );
+        for hour in 0..24 {
+            let horiz =
+                Horizontal::from_equitorial(&polaris, &geo, &GMST(Angle::Hour(hour as f64)));
+            assert_float_absolute_eq!(horiz.altitude.value().to_deg(), 52.5, 1.0);
+            let azimuth = horiz.azimuth.value().to_deg();
+            assert!(azimuth <= 1.5 || azimuth >= 358.5, "{}", azimuth);
+        }
+
+        // Equatorial stars rise due east six hours before they culminate
+        let rising = equitorial(Angle::Hour(6.0), Angle::Degree(0.0));
+        let horiz = Horizontal::from_equitorial(&rising, &site(30.0, 0.0), &GMST(Angle::Hour(0.0)));
+        assert_float_absolute_eq!(horiz.azimuth.value().to_deg(), 90.0, 1e-9);
+        assert_float_absolute_eq!(horiz.altitude.value().to_deg(), 0.0, 1e-9);
+    }
+
+    #[test]
+    fn stereo_project() {
+        let zenith = Horizontal {
+            altitude: Altitude::new(&Angle::Degree(90.0)),
+            azimuth: Azimuth::new(&Angle::Degree(0.0)),
+        };
+        assert_float_absolute_eq!(zenith.stereo_project().radius, 0.0);
+
+        let horizon = Horizontal {
+            altitude: Altitude::new(&Angle::Degree(0.0)),
+            azimuth: Azimuth::new(&Angle::Degree(90.0)),
+        };
+        let projected = horizon.stereo_project();
+        assert_float_absolute_eq!(projected.radius, 2.0);
+        assert_float_absolute_eq!(projected.angle.to_deg(), 90.0);
+    }
+
+    #[test]
+    fn separation() {
+        // Meeus, Astronomical Algorithms, Example 17.a: Arcturus and Spica
+        let arcturus = equitorial(Angle::Degree(213.9154), Angle::Degree(19.1825));
+        let spica = equitorial(Angle::Degree(201.2983), Angle::Degree(-11.1614));
+        assert_float_absolute_eq!(arcturus.separation(&spica).to_deg(), 32.7930, 1e-4);
+        assert_float_absolute_eq!(spica.separation(&arcturus).to_deg(), 32.7930, 1e-4);
+
+        // Tiny and antipodal separations stay accurate
+        let origin = equitorial(Angle::Degree(10.0), Angle::Degree(-30.0));
+        let close = equitorial(
+            Angle::Degree(10.0),
+            Angle::from_mas(-30.0 * 3_600_000.0 + 1.0),
+        );
+        assert_float_absolute_eq!(origin.separation(&close).to_mas(), 1.0, 1e-6);
+        let antipode = equitorial(Angle::Degree(190.0), Angle::Degree(30.0));
+        assert_float_absolute_eq!(origin.separation(&antipode).to_deg(), 180.0, 1e-9);
+    }
+
+    #[test]
+    fn position_angle() {
+        let origin = equitorial(Angle::Degree(0.0), Angle::Degree(0.0));
+        for (ra, dec, position_angle) in [
+            (0.0, 1.0, 0.0),
+            (1.0, 0.0, 90.0),
+            (0.0, -1.0, 180.0),
+            (-1.0, 0.0, 270.0),
+        ] {
+            let other = equitorial(Angle::Degree(ra), Angle::Degree(dec));
+            assert_float_absolute_eq!(origin.position_angle(&other).to_deg(), position_angle, 1e-9);
+        }
+
+        // Offsets invert the position angle and separation
+        let vega = equitorial(Angle::Degree(279.23473), Angle::Degree(38.78369));
+        for (position_angle, separation) in
+            [(0.0, 1.0), (45.0, 0.001), (173.0, 20.0), (300.0, 89.0)]
+        {
+            let companion =
+                vega.offset_by(&Angle::Degree(position_angle), &Angle::Degree(separation));
+            assert_float_absolute_eq!(vega.separation(&companion).to_deg(), separation, 1e-9);
+            assert_float_absolute_eq!(
+                vega.position_angle(&companion).to_deg(),
+                position_angle,
+                1e-7
+            );
+        }
+    }
+
+    #[test]
+    fn cartesian() {
+        let vega = equitorial(Angle::Degree(279.23473), Angle::Degree(38.78369));
+        let v = Cartesian::from(vega);
+        assert_float_absolute_eq!(v.norm(), 1.0);
+        let back = Equitorial::from(v * 3.0);
+        assert_float_absolute_eq!(back.right_ascension.value().to_deg(), 279.23473, 1e-9);
+        assert_float_absolute_eq!(back.declination.value().to_deg(), 38.78369, 1e-9);
+
+        let pole = Cartesian::from(equitorial(Angle::Degree(0.0), Angle::Degree(90.0)));
+        assert_float_absolute_eq!(pole.z, 1.0);
+
+        // dot product of unit vectors is the cosine of the separation
+        let arcturus = equitorial(Angle::Degree(213.9154), Angle::Degree(19.1825));
+        let spica = equitorial(Angle::Degree(201.2983), Angle::Degree(-11.1614));
+        assert_float_absolute_eq!(
+            Cartesian::from(arcturus).dot(&Cartesian::from(spica)),
+            arcturus.separation(&spica).cos()
+        );
+
+        let x = Cartesian {
+            x: 1.0,
+            y: 0.0,
+            z: 0.0,
+        };
+        let y = Cartesian {
+            x: 0.0,
+            y: 1.0,
+            z: 0.0,
+        };
+        assert_eq!(
+            x.cross(&y),
+            Cartesian {
+                x: 0.0,
+                y: 0.0,
+                z: 1.0
+            }
+        );
+        assert_eq!(
+            y.cross(&x),
+            Cartesian {
+                x: 0.0,
+                y: 0.0,
+                z: -1.0
+            }
+        );
+        assert_eq!(x + y - x, y);
+
+        let n = Cartesian {
+            x: 3.0,
+            y: 0.0,
+            z: 4.0,
+        };
+        assert_float_absolute_eq!(n.norm(), 5.0);
+        assert_eq!(
+            n.normalize(),
+            Cartesian {
+                x: 0.6,
+                y: 0.0,
+                z: 0.8
+            }
+        );
+    }
+
+    #[test]
+    fn matrix() {
+        let x = Cartesian {
+            x: 1.0,
+            y: 0.0,
+            z: 0.0,
+        };
+        let quarter = Angle::Degree(90.0);
+
+        // frame rotations move vectors the opposite way
+        let v = Matrix3::rotation_z(&quarter).apply(&x);
+        assert_float_absolute_eq!(v.y, -1.0);
+        let v = Matrix3::rotation_y(&quarter) * x;
+        assert_float_absolute_eq!(v.z, 1.0);
+        let v = Matrix3::rotation_x(&quarter)
+            * Cartesian {
+                x: 0.0,
+                y: 1.0,
+                z: 0.0,
+            };
+        assert_float_absolute_eq!(v.z, -1.0);
+
+        // rotations are orthogonal and compose
+        let m =
+            Matrix3::rotation_z(&Angle::Degree(30.0)) * Matrix3::rotation_x(&Angle::Degree(-50.0));
+        let identity = m * m.transpose();
+        for i in 0..3 {
+            for j in 0..3 {
+                assert_float_absolute_eq!(identity.0[i][j], Matrix3::IDENTITY.0[i][j]);
+            }
+        }
+        let v = Cartesian {
+            x: 0.3,
+            y: -0.2,
+            z: 0.9,
+        };
+        let composed = m * v;
+        let stepwise = Matrix3::rotation_z(&Angle::Degree(30.0))
+            .apply(&Matrix3::rotation_x(&Angle::Degree(-50.0)).apply(&v));
+        assert_float_absolute_eq!((composed - stepwise).norm(), 0.0);
+        assert_float_absolute_eq!(composed.norm(), v.norm());
+    }
+
+    #[test]
+    fn obliquity() {
+        let j2000 = JulianDate::from_jd(2451545.0);
+        assert_float_absolute_eq!(
+            mean_obliquity(&j2000, ObliquityModel::Iau1980).to_deg(),
+            84381.448 / 3600.0
+        );
+        assert_float_absolute_eq!(
+            mean_obliquity(&j2000, ObliquityModel::Iau2006).to_deg(),
+            84381.406 / 3600.0
+        );
+
+        // Meeus, Astronomical Algorithms, Example 22.a: 1987 April 10, 0h TD
+        let date = JulianDate::from_jd(2446895.5);
+        assert_float_absolute_eq!(
+            mean_obliquity(&date, ObliquityModel::Iau1980).to_deg(),
+            23.0 + 26.0 / 60.0 + 27.407 / 3600.0,
+            1e-3 / 3600.0
+        );
+    }
+
+    #[test]
+    fn ecliptic() {
+        // Meeus, Astronomical Algorithms, Example 13.a: Pollux
+        let obliquity = Angle::Degree(23.4392911);
+        let pollux = Equitorial {
+            right_ascension: RightAscension::new(&Angle::Degree(116.328942)),
+            declination: Declination::new(&Angle::Degree(28.026183)),
+        };
+        let ecl = Ecliptic::from_equitorial(&pollux, &obliquity);
+        assert_float_absolute_eq!(ecl.longitude.value().to_deg(), 113.215630, 1e-6);
+        assert_float_absolute_eq!(ecl.latitude.value().to_deg(), 6.684170, 1e-6);
+
+        let back = Equitorial::from_ecliptic(&ecl, &obliquity);
+        assert_float_absolute_eq!(back.right_ascension.value().to_deg(), 116.328942, 1e-9);
+        assert_float_absolute_eq!(back.declination.value().to_deg(), 28.026183, 1e-9);
+
+        // Winter solstice, longitudes past 180 degrees don't wrap to negative
+        let solstice = Ecliptic::from_equitorial(
+            &Equitorial {
+                right_ascension: RightAscension::new(&Angle::Degree(270.0)),
+                declination: Declination::new(&-obliquity),
+            },
+            &obliquity,
+        );
+        assert_float_absolute_eq!(solstice.longitude.value().to_deg(), 270.0, 1e-9);
+        assert_float_absolute_eq!(solstice.latitude.value().to_deg(), 0.0, 1e-9);
+    }
+}
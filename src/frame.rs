@@ -0,0 +1,380 @@
+/*!
+Reference frames and rotations between them
+
+Positions are unit [`Cartesian`] vectors tagged with their [`Frame`]. Every frame is defined by the rotation from ICRS,
+so transforming between any two frames is a single matrix product.
+
+> NOTE: the rotations only cover the orientation of the frames. Proper motion, parallax, aberration, nutation and refraction are ignored.
+ */
+
+use crate::angle::{Angle, PI, PI_HALF};
+use crate::coord::{
+    mean_obliquity, Cartesian, ConstrainedAngle, Geographic, Matrix3, ObliquityModel,
+    NCP_LONGITUDE, NGP_DECLINATION, NGP_RIGHT_ASCENSION,
+};
+use crate::time::scale::{Tt, Ut1};
+use crate::time::{JulianDate, GMST};
+
+/// Celestial and terrestrial reference frames
+#[derive(Debug, Copy, Clone)]
+pub enum Frame {
+    /// International Celestial Reference System
+    Icrs,
+    /// Mean equator and equinox of J2000, offset from ICRS by the frame bias
+    EquatorialJ2000,
+    /// FK5 catalog frame (J2000), offset from ICRS by the FK5 orientation error
+    Fk5,
+    /// FK4, mean equator and equinox of B1950
+    Fk4,
+    /// Galactic coordinates (IAU 1958, expressed in ICRS)
+    Galactic,
+    /// Mean ecliptic and equinox of date
+    EclipticOfDate(JulianDate),
+    /// Mean equator and equinox of date
+    EquatorialOfDate(JulianDate),
+    /**
+    Local horizon (longitude is the azimuth from north through east, latitude is the altitude)
+
+    The rotation of the Earth needs UT1, while the precession of the equator needs TT.
+     */
+    Horizontal {
+        location: Geographic,
+        ut1: Ut1,
+        tt: Tt,
+    },
+}
+
+/**
+Frame bias from ICRS to the mean equator and equinox of J2000 (IERS 2003).
+
+<https://www.iers.org/IERS/EN/Publications/TechnicalNotes/tn32.html>
+ */
+fn frame_bias() -> Matrix3 {
+    // Offsets of the J2000 pole (eta, xi) and equinox (d_alpha) from ICRS
+    let d_alpha = Angle::from_arcsec(-0.0146);
+    let xi = Angle::from_arcsec(-0.041775) * Angle::from_arcsec(84381.448).sin();
+    let eta = Angle::from_arcsec(-0.0068192);
+    Matrix3::rotation_x(&-eta) * Matrix3::rotation_y(&xi) * Matrix3::rotation_z(&d_alpha)
+}
+
+/**
+Orientation of the FK5 catalog frame with respect to ICRS (Hipparcos), from Mignard & Froeschlé (2000) like SOFA `iauFk5hip`.
+
+> NOTE: the spin of FK5 with respect to Hipparcos (about 0.3 mas/yr) is ignored, like the proper motions.
+ */
+fn fk5() -> Matrix3 {
+    // Rotation vector of FK5 with respect to Hipparcos
+    let (x, y, z) = (
+        Angle::from_mas(-19.9),
+        Angle::from_mas(-9.1),
+        Angle::from_mas(22.9),
+    );
+    // NOTE: the angles are small enough for the order of the rotations not to matter.
+    (Matrix3::rotation_x(&x) * Matrix3::rotation_y(&y) * Matrix3::rotation_z(&z)).transpose()
+}
+
+/**
+Precession (IAU 1976) from the mean equator and equinox of J2000 to a date.
+
+<https://en.wikipedia.org/wiki/Axial_precession#Equations>
+ */
+fn precession(julian_date: &JulianDate) -> Matrix3 {
+    // Julian centuries since J2000
+    let t = julian_date.julian_centuries();
+    let zeta = Angle::from_arcsec(t * (2306.2181 + t * (0.30188 + t * 0.017998)));
+    let z = Angle::from_arcsec(t * (2306.2181 + t * (1.09468 + t * 0.018203)));
+    let theta = Angle::from_arcsec(t * (2004.3109 + t * (-0.42665 + t * -0.041833)));
+    Matrix3::rotation_z(&-z) * Matrix3::rotation_y(&theta) * Matrix3::rotation_z(&-zeta)
+}
+
+/**
+Rotation from FK5 J2000 to FK4 B1950, the position part of the Standish (1982) / Aoki et al. (1983) transformation.
+
+> NOTE: E-terms of aberration and the fictitious FK4 proper motions are ignored, so positions agree to about an arc second.
+ */
+fn fk5_to_fk4() -> Matrix3 {
+    Matrix3([
+        [0.9999256782, -0.0111820611, -0.0048579477],
+        [0.0111820610, 0.9999374784, -0.0000271765],
+        [0.0048579479, -0.0000271474, 0.9999881997],
+    ])
+    .transpose()
+}
+
+/// Rotation from ICRS to galactic coordinates, built from the galactic pole and the longitude of the celestial pole.
+fn galactic() -> Matrix3 {
+    Matrix3::rotation_z(&(Angle::Radian(PI) - Angle::Degree(NCP_LONGITUDE)))
+        * Matrix3::rotation_y(&(Angle::Radian(PI_HALF) - Angle::Degree(NGP_DECLINATION)))
+        * Matrix3::rotation_z(&Angle::Degree(NGP_RIGHT_ASCENSION))
+}
+
+/**
+Rotation from the mean equator of date to the local horizon, using the IAU 2006 [`GMST`].
+
+The horizon frame has x to the north, y to the west and z to the zenith, so azimuths (north through east) are negated longitudes.
+ */
+fn horizon(location: &Geographic, ut1: &Ut1, tt: &Tt) -> Matrix3 {
+    let local_sidereal_time = GMST::iau2006(&ut1.0, &tt.0).0 + location.longitude.value();
+    Matrix3::rotation_z(&Angle::Radian(PI))
+        * Matrix3::rotation_y(&(Angle::Radian(PI_HALF) - location.latitude.value()))
+        * Matrix3::rotation_z(&local_sidereal_time)
+}
+
+impl Frame {
+    /// Rotation from ICRS to this frame
+    pub fn from_icrs(&self) -> Matrix3 {
+        match self {
+            Self::Icrs => Matrix3::IDENTITY,
+            Self::EquatorialJ2000 => frame_bias(),
+            Self::Fk5 => fk5(),
+            Self::Fk4 => fk5_to_fk4() * fk5(),
+            Self::Galactic => galactic(),
+            Self::EquatorialOfDate(date) => precession(date) * frame_bias(),
+            Self::EclipticOfDate(date) => {
+                Matrix3::rotation_x(&mean_obliquity(date, ObliquityModel::Iau1980))
+                    * precession(date)
+                    * frame_bias()
+            }
+            Self::Horizontal { location, ut1, tt } => {
+                horizon(location, ut1, tt) * precession(&tt.0) * frame_bias()
+            }
+        }
+    }
+
+    /// Rotation from this frame to another
+    pub fn rotation_to(&self, frame: &Frame) -> Matrix3 {
+        // NOTE: the transpose of a rotation is its inverse.
+        frame.from_icrs() * self.from_icrs().transpose()
+    }
+}
+
+/// Direction in a reference frame
+#[derive(Debug, Copy, Clone)]
+pub struct Position {
+    /// Frame of the vector
+    pub frame: Frame,
+    /// Unit vector
+    pub vector: Cartesian,
+}
+
+impl Position {
+    /**
+    Position from spherical coordinates in a frame (e.g. right ascension and declination, or galactic longitude and latitude).
+
+    For [`Frame::Horizontal`] the longitude is the azimuth measured from north through east and the latitude is the altitude.
+     */
+    pub fn new(frame: Frame, longitude: &Angle, latitude: &Angle) -> Self {
+        let longitude = match frame {
+            Frame::Horizontal { .. } => -*longitude,
+            _ => *longitude,
+        };
+        let (sin_lon, cos_lon) = longitude.sin_cos();
+        let (sin_lat, cos_lat) = latitude.sin_cos();
+        Self {
+            frame,
+            vector: Cartesian {
+                x: cos_lat * cos_lon,
+                y: cos_lat * sin_lon,
+                z: sin_lat,
+            },
+        }
+    }
+
+    /// Longitude in the frame between 0 and 360 degrees (azimuth for [`Frame::Horizontal`])
+    pub fn longitude(&self) -> Angle {
+        let longitude = Angle::atan2(self.vector.y, self.vector.x);
+        match self.frame {
+            Frame::Horizontal { .. } => -longitude,
+            _ => longitude,
+        }
+        .normalize_0_360()
+    }
+
+    /// Latitude in the frame (altitude for [`Frame::Horizontal`])
+    pub fn latitude(&self) -> Angle {
+        Angle::atan2(self.vector.z, self.vector.x.hypot(self.vector.y))
+    }
+
+    /// Same direction expressed in another frame
+    pub fn transform_to(&self, frame: Frame) -> Self {
+        Self {
+            frame,
+            vector: self.frame.rotation_to(&frame).apply(&self.vector),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::angle::{DegMinSec, HourMinSec, Sign};
+    use crate::coord::*;
+    use crate::frame::*;
+    use crate::time::delta_t::EspenakMeeus;
+
+    fn frames() -> Vec<Frame> {
+        let date = JulianDate::from_jd(2462088.69);
+        vec![
+            Frame::Icrs,
+            Frame::EquatorialJ2000,
+            Frame::Fk5,
+            Frame::Fk4,
+            Frame::Galactic,
+            Frame::EclipticOfDate(date),
+            Frame::EquatorialOfDate(date),
+            Frame::Horizontal {
+                location: Geographic {
+                    latitude: Latitude::new(&Angle::Degree(52.5)),
+                    longitude: Longitude::new(&Angle::Degree(-1.9)),
+                },
+                ut1: Tt(date).to_ut1(&EspenakMeeus),
+                tt: Tt(date),
+            },
+        ]
+    }
+
+    #[test]
+    fn orthogonal() {
+        for frame in frames() {
+            let m = frame.from_icrs();
+            let identity = m * m.transpose();
+            for i in 0..3 {
+                for j in 0..3 {
+                    assert_float_absolute_eq!(identity.0[i][j], Matrix3::IDENTITY.0[i][j], 1e-9);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let position = Position::new(
+            Frame::Icrs,
+            &Angle::Degree(279.23473),
+            &Angle::Degree(38.78369),
+        );
+        for from in frames() {
+            for to in frames() {
+                let back = position
+                    .transform_to(from)
+                    .transform_to(to)
+                    .transform_to(Frame::Icrs);
+                assert_float_absolute_eq!(back.longitude().to_deg(), 279.23473, 1e-7);
+                assert_float_absolute_eq!(back.latitude().to_deg(), 38.78369, 1e-7);
+            }
+        }
+    }
+
+    #[test]
+    fn matches_formulae() {
+        let eq = Equitorial {
+            right_ascension: RightAscension::new(&Angle::Degree(116.328942)),
+            declination: Declination::new(&Angle::Degree(28.026183)),
+        };
+
+        // Galactic
+        let gal = Galactic::from_equitorial(&eq);
+        let position = Position::new(
+            Frame::Icrs,
+            &eq.right_ascension.value(),
+            &eq.declination.value(),
+        )
+        .transform_to(Frame::Galactic);
+        assert_float_absolute_eq!(
+            position.longitude().to_deg(),
+            gal.longitude.value().to_deg(),
+            1e-9
+        );
+        assert_float_absolute_eq!(
+            position.latitude().to_deg(),
+            gal.latitude.value().to_deg(),
+            1e-9
+        );
+
+        // Ecliptic (no precession at J2000)
+        let j2000 = JulianDate::from_jd(2451545.0);
+        let ecl = Ecliptic::from_equitorial(&eq, &mean_obliquity(&j2000, ObliquityModel::Iau1980));
+        let position = Position::new(
+            Frame::EquatorialJ2000,
+            &eq.right_ascension.value(),
+            &eq.declination.value(),
+        )
+        .transform_to(Frame::EclipticOfDate(j2000));
+        assert_float_absolute_eq!(
+            position.longitude().to_deg(),
+            ecl.longitude.value().to_deg(),
+            1e-9
+        );
+        assert_float_absolute_eq!(
+            position.latitude().to_deg(),
+            ecl.latitude.value().to_deg(),
+            1e-9
+        );
+
+        // Horizontal
+        let location = Geographic {
+            latitude: Latitude::new(&Angle::Degree(-33.9)),
+            longitude: Longitude::new(&Angle::Degree(18.4)),
+        };
+        let ut1 = Ut1(JulianDate::from_jd(2460000.3));
+        let tt = ut1.to_tt(&EspenakMeeus);
+        let horiz = Horizontal::from_equitorial(&eq, &location, &GMST::iau2006(&ut1.0, &tt.0));
+        let position = Position::new(
+            Frame::EquatorialOfDate(tt.0),
+            &eq.right_ascension.value(),
+            &eq.declination.value(),
+        )
+        .transform_to(Frame::Horizontal { location, ut1, tt });
+        assert_float_absolute_eq!(
+            position.longitude().to_deg(),
+            horiz.azimuth.value().to_deg(),
+            1e-9
+        );
+        assert_float_absolute_eq!(
+            position.latitude().to_deg(),
+            horiz.altitude.value().to_deg(),
+            1e-9
+        );
+    }
+
+    #[test]
+    fn precession() {
+        // Meeus, Astronomical Algorithms, Example 21.b: theta Persei at 2028 November 13.19 TD
+        // NOTE: the J2000 position has been moved by the proper motion over the 28.867 years beforehand.
+        let theta_persei = Position::new(
+            Frame::EquatorialJ2000,
+            &Angle::from(HourMinSec(
+                Sign::Positive,
+                2,
+                44,
+                11.986 + 0.03425 * 28.86705,
+            )),
+            &Angle::from(DegMinSec(Sign::Positive, 49, 13, 42.48 - 0.0895 * 28.86705)),
+        )
+        .transform_to(Frame::EquatorialOfDate(JulianDate::from_jd(2462088.69)));
+        assert_float_absolute_eq!(theta_persei.longitude().to_deg(), 41.547213, 2e-6);
+        assert_float_absolute_eq!(theta_persei.latitude().to_deg(), 49.348483, 2e-6);
+    }
+
+    #[test]
+    fn frame_bias() {
+        // The ICRS pole is 17 mas from the J2000 mean pole
+        let pole = Position::new(Frame::Icrs, &Angle::Degree(0.0), &Angle::Degree(90.0))
+            .transform_to(Frame::EquatorialJ2000);
+        assert_float_absolute_eq!(
+            90.0 - pole.latitude().to_deg(),
+            Angle::from_mas(17.9).to_deg(),
+            Angle::from_mas(0.1).to_deg()
+        );
+    }
+
+    #[test]
+    #[allow(clippy::excessive_precision)] // NOTE: reference values have that much precision
+    fn fk5_orientation() {
+        // FK5 to Hipparcos rotation from the SOFA test suite (t_sofa_c.c, iauFk5hip)
+        let r5h = Frame::Fk5.from_icrs().transpose();
+        assert_float_absolute_eq!(r5h.0[0][0], 0.9999999999999928638, 1e-14);
+        assert_float_absolute_eq!(r5h.0[0][1], 0.1110223351022919694e-6, 1e-14);
+        assert_float_absolute_eq!(r5h.0[0][2], 0.4411803962536558154e-7, 1e-14);
+        assert_float_absolute_eq!(r5h.0[1][2], -0.9647792498984142358e-7, 1e-14);
+    }
+}
@@ -1,39 +1,42 @@
 use chrono::{Local, TimeZone};
 use starstuff_types::angle::Angle;
 use starstuff_types::coord::{
-    Declination, Equitorial, Geographic, Horizontal, Latitude, Longitude, RightAscension,
+    ConstrainedAngle, Declination, Equitorial, Geographic, Latitude, Longitude, RightAscension,
 };
 use starstuff_types::star::{Star, StarCoordinates};
 use starstuff_types::time::*;
 
 fn main() {
     let mut stars: Vec<Star> = vec![Star {
-        coordinates: StarCoordinates::Equitorial(Equitorial {
-            right_ascension: RightAscension(Angle::Degree(10.0)),
-            declination: Declination(Angle::Degree(20.0)),
-        }),
-        v_mag: 0.0,
-        harvard: 1,
-        name: String::from("dummy"),
+        coordinates: Some(StarCoordinates::Equitorial(Equitorial {
+            right_ascension: RightAscension::new(&Angle::Degree(10.0)),
+            declination: Declination::new(&Angle::Degree(20.0)),
+        })),
+        v_mag: Some(0.0),
+        harvard: Some(1),
+        name: Some(String::from("dummy")),
+        ..Default::default()
     }];
 
     println!("{:?}", &stars[0]);
 
     let geo = Geographic {
-        latitude: Latitude(Angle::Degree(0.0)),
-        longitude: Longitude(Angle::Degree(0.0)),
+        latitude: Latitude::new(&Angle::Degree(0.0)),
+        longitude: Longitude::new(&Angle::Degree(0.0)),
     };
 
-    let sidereal_time: GMST = GMST::from(JulianDate::from(
-        Local.with_ymd_and_hms(2000, 1, 1, 12, 0, 0).unwrap(),
-    ));
+    // NOTE: the Earth rotation angle needs UT1 rather than civil (UTC) time.
+    let ut1 = scale::Utc::from(Local.with_ymd_and_hms(2000, 1, 1, 12, 0, 0).unwrap())
+        .to_ut1(&delta_t::EspenakMeeus);
+    let sidereal_time: GMST = GMST::from(ut1.0);
 
     for star in &mut stars {
-        let horiz_coord = StarCoordinates::Stereo(
-            Horizontal::from_equitorial(&Equitorial::from(star.coordinates), &geo, &sidereal_time)
-                .stereo_project(),
-        );
-        star.coordinates = horiz_coord;
+        let name = star.name.as_deref().unwrap_or("unnamed star");
+        match star.coordinates.map(|c| c.to_stereo(&geo, &sidereal_time)) {
+            Some(Ok(stereo)) => star.coordinates = Some(StarCoordinates::Stereo(stereo)),
+            Some(Err(why)) => eprintln!("skipping {}: {}", name, why),
+            None => eprintln!("skipping {}: no coordinates", name),
+        }
     }
 
     println!("{:?}", stars[0]);
@@ -0,0 +1,139 @@
+/*!
+Fixed-width text parsing shared by the star catalogs and the IERS tables
+
+- [`fields`]: extract and parse the fields of a line
+- [`reader`]: stream records from any (possibly compressed) source, one line at a time
+ */
+
+pub mod fields;
+pub mod reader;
+
+use std::fmt;
+use std::ops::Range;
+
+pub use reader::{CatalogReader, CatalogRecord};
+
+/// Determines if a parsed record is valid. User can implement this to require certain fields or conditions.
+pub trait ValidParse {
+    fn is_valid_parse(&self) -> bool;
+}
+
+/// Underlying cause of a [`ParseError::Field`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// The line ends before the field.
+    Truncated,
+    /// A field the record can't do without (e.g. its catalog number) is blank.
+    Missing,
+    /// The field text could not be parsed.
+    Invalid {
+        /// Trimmed field text
+        text: String,
+        /// Why the text was rejected
+        reason: String,
+    },
+}
+
+/// Error raised while reading or parsing a catalog or table.
+#[derive(Debug)]
+pub enum ParseError {
+    /// The file could not be opened or read.
+    Io(std::io::Error),
+    /// A field of a record could not be parsed.
+    Field {
+        /// 1-based line number, if the record was read from a file
+        line: Option<usize>,
+        /// 0-based byte range of the field within the line
+        columns: Range<usize>,
+        /// Field label as given in the catalog ReadMe (e.g. `RAh`, `H8`, `04`)
+        field: &'static str,
+        /// Underlying cause
+        kind: ParseErrorKind,
+    },
+}
+
+impl ParseError {
+    /// Error for a field of a record.
+    pub fn field(columns: Range<usize>, field: &'static str, kind: ParseErrorKind) -> Self {
+        Self::Field {
+            line: None,
+            columns,
+            field,
+            kind,
+        }
+    }
+
+    /// Attach the line number the record was read from.
+    pub fn at_line(self, line_number: usize) -> Self {
+        match self {
+            Self::Field {
+                columns,
+                field,
+                kind,
+                ..
+            } => Self::Field {
+                line: Some(line_number),
+                columns,
+                field,
+                kind,
+            },
+            other => other,
+        }
+    }
+
+    /// Cause of a field error, `None` for I/O errors.
+    pub fn kind(&self) -> Option<&ParseErrorKind> {
+        match self {
+            Self::Field { kind, .. } => Some(kind),
+            Self::Io(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(why) => write!(f, "couldn't read input: {}", why),
+            Self::Field {
+                line,
+                columns,
+                field,
+                kind,
+            } => {
+                if let Some(line) = line {
+                    write!(f, "line {}, ", line)?;
+                }
+                // NOTE: byte ranges are reported 1-based and inclusive like the catalog ReadMe files.
+                write!(
+                    f,
+                    "bytes {}-{} ({}): ",
+                    columns.start + 1,
+                    columns.end,
+                    field
+                )?;
+                match kind {
+                    ParseErrorKind::Truncated => write!(f, "line ends before field"),
+                    ParseErrorKind::Missing => write!(f, "required field is blank"),
+                    ParseErrorKind::Invalid { text, reason } => {
+                        write!(f, "invalid value \"{}\": {}", text, reason)
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(why) => Some(why),
+            Self::Field { .. } => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for ParseError {
+    fn from(why: std::io::Error) -> Self {
+        Self::Io(why)
+    }
+}
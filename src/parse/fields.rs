@@ -0,0 +1,112 @@
+//! Fixed-width field helpers
+
+use super::{ParseError, ParseErrorKind};
+
+use std::fmt::Display;
+use std::ops::Range;
+use std::str::FromStr;
+
+/**
+Parse an already extracted field into a given type, `None` if the field is blank.
+
+`columns` and `field` are only used to describe the field in the error.
+*/
+pub fn parse_field<T>(
+    text: &str,
+    columns: Range<usize>,
+    field: &'static str,
+) -> Result<Option<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    // Need to trim because numbers are space padded in catalogs
+    match text.trim() {
+        "" => Ok(None),
+        t => t.parse::<T>().map(Some).map_err(|why| {
+            ParseError::field(
+                columns,
+                field,
+                ParseErrorKind::Invalid {
+                    text: t.to_string(),
+                    reason: why.to_string(),
+                },
+            )
+        }),
+    }
+}
+
+/**
+Extract a fixed-width field from a line.
+
+Bytes past the end of the line are treated as blank because catalogs strip trailing spaces.
+Use [`required`] to tell a truncated line from a blank field.
+*/
+pub fn slice_field<'a>(
+    line: &'a str,
+    columns: Range<usize>,
+    field: &'static str,
+) -> Result<&'a str, ParseError> {
+    let end = columns.end.min(line.len());
+    let start = columns.start.min(end);
+    line.get(start..end).ok_or_else(|| {
+        ParseError::field(
+            columns,
+            field,
+            ParseErrorKind::Invalid {
+                text: String::from_utf8_lossy(&line.as_bytes()[start..end]).into_owned(),
+                reason: String::from("field is not on a character boundary"),
+            },
+        )
+    })
+}
+
+/// Parse a fixed-width field of a line into a given type, `None` if the field is blank.
+pub fn parse_fixed<T>(
+    line: &str,
+    columns: Range<usize>,
+    field: &'static str,
+) -> Result<Option<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    parse_field(slice_field(line, columns.clone(), field)?, columns, field)
+}
+
+/**
+Check that a required fixed-width field was parsed.
+
+Reports [`ParseErrorKind::Truncated`] if the line ends before the field and [`ParseErrorKind::Missing`] if the field is blank.
+*/
+pub fn required<T>(
+    line: &str,
+    value: &Option<T>,
+    columns: Range<usize>,
+    field: &'static str,
+) -> Result<(), ParseError> {
+    match value {
+        Some(_) => Ok(()),
+        None if line.len() < columns.end => {
+            Err(ParseError::field(columns, field, ParseErrorKind::Truncated))
+        }
+        None => Err(ParseError::field(columns, field, ParseErrorKind::Missing)),
+    }
+}
+
+/**
+Check that a line doesn't end before a fixed-width field.
+
+Unlike [`required`], a blank field isn't an error: records with blank fields are left to [`super::ValidParse`] (e.g. objects removed from the Yale catalog).
+*/
+pub fn not_truncated(
+    line: &str,
+    columns: Range<usize>,
+    field: &'static str,
+) -> Result<(), ParseError> {
+    if line.len() < columns.end {
+        Err(ParseError::field(columns, field, ParseErrorKind::Truncated))
+    } else {
+        Ok(())
+    }
+}
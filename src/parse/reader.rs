@@ -0,0 +1,273 @@
+/*!
+Streaming record reader
+
+Records are parsed lazily, one line at a time, from any [`BufRead`] source (files, stdin, in-memory bytes, ...).
+Wrap a source with [`decompress`] to read gzip or bzip2 streams.
+*/
+
+use super::{ParseError, ValidParse};
+
+use bzip2::bufread::MultiBzDecoder;
+use flate2::bufread::MultiGzDecoder;
+
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Lines, Read};
+use std::marker::PhantomData;
+use std::path::Path;
+
+/// Leading bytes of a gzip stream
+const GZIP_MAGIC: &[u8] = &[0x1f, 0x8b];
+/// Leading bytes of a bzip2 stream
+const BZIP2_MAGIC: &[u8] = b"BZh";
+
+/**
+Wrap a reader so gzip (`.gz`) and bzip2 (`.bz2`) compressed catalogs are decompressed transparently.
+
+The compression is detected from the leading magic bytes, so plain text passes through untouched.
+*/
+pub fn decompress<'a, R: BufRead + 'a>(mut reader: R) -> io::Result<Box<dyn BufRead + 'a>> {
+    // NOTE: pipes can fill the buffer with fewer bytes than the magic, keep reading until it's complete (or EOF).
+    let mut magic = Vec::with_capacity(BZIP2_MAGIC.len());
+    while magic.len() < BZIP2_MAGIC.len() {
+        let buffer = reader.fill_buf()?;
+        if buffer.is_empty() {
+            break;
+        }
+        let n = buffer.len().min(BZIP2_MAGIC.len() - magic.len());
+        magic.extend_from_slice(&buffer[..n]);
+        reader.consume(n);
+    }
+    let gzip = magic.starts_with(GZIP_MAGIC);
+    let bzip2 = magic.starts_with(BZIP2_MAGIC);
+    // Put the magic bytes back in front of the stream
+    let reader = io::Cursor::new(magic).chain(reader);
+
+    Ok(if gzip {
+        Box::new(BufReader::new(MultiGzDecoder::new(reader)))
+    } else if bzip2 {
+        Box::new(BufReader::new(MultiBzDecoder::new(reader)))
+    } else {
+        Box::new(reader)
+    })
+}
+
+/// A catalog record that can be parsed from a single line.
+pub trait CatalogRecord: TryFrom<String, Error = ParseError> + ValidParse {
+    /// Width lines are padded to before parsing, `None` to parse lines as they are.
+    const PAD: Option<usize> = None;
+}
+
+/**
+Iterator over the records of a catalog.
+
+Yields a `Result` for every non-blank line so callers can decide how to handle malformed records.
+Lines that fail to parse, including truncated lines, are always yielded as errors.
+By default, records that parse but fail [`ValidParse`] (e.g. objects removed from the Yale catalog) are skipped, like filtered records.
+*/
+pub struct CatalogReader<R, T> {
+    lines: Lines<R>,
+    line_number: usize,
+    pad: Option<usize>,
+    skip_invalid: bool,
+    done: bool,
+    record: PhantomData<T>,
+}
+
+impl<R: BufRead, T: CatalogRecord> CatalogReader<R, T> {
+    /// Read records from a buffered reader.
+    pub fn new(reader: R) -> Self {
+        Self {
+            lines: reader.lines(),
+            line_number: 0,
+            pad: T::PAD,
+            skip_invalid: true,
+            done: false,
+            record: PhantomData,
+        }
+    }
+
+    /**
+    Pad lines with spaces to a given width before parsing (defaults to [`CatalogRecord::PAD`]).
+
+    > NOTE: a padded line can't be told from a truncated one, its blank fields are left to [`ValidParse`].
+    */
+    pub fn pad(mut self, width: Option<usize>) -> Self {
+        self.pad = width;
+        self
+    }
+
+    /**
+    Skip records that fail [`ValidParse`] instead of yielding them (defaults to `true`).
+
+    Only records that parse are affected: parse errors are yielded either way.
+    */
+    pub fn skip_invalid(mut self, skip: bool) -> Self {
+        self.skip_invalid = skip;
+        self
+    }
+}
+
+impl<T: CatalogRecord> CatalogReader<Box<dyn BufRead>, T> {
+    /// Open a catalog file, decompressing gzip and bzip2 files transparently.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, ParseError> {
+        Ok(Self::new(decompress(BufReader::new(File::open(path)?))?))
+    }
+}
+
+impl<R: BufRead, T: CatalogRecord> Iterator for CatalogReader<R, T> {
+    type Item = Result<T, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done {
+            let line = match self.lines.next()? {
+                Ok(line) => line,
+                Err(why) => {
+                    // NOTE: stop after an I/O error rather than yielding it forever.
+                    self.done = true;
+                    return Some(Err(why.into()));
+                }
+            };
+            self.line_number += 1;
+
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            // NOTE: need to pad the line with empty space because it will terminate early with empty fields at the end.
+            let s = match self.pad {
+                Some(n) => format!("{:<width$}", line, width = n),
+                None => line,
+            };
+
+            match T::try_from(s) {
+                Ok(record) if self.skip_invalid && !record.is_valid_parse() => continue,
+                Ok(record) => return Some(Ok(record)),
+                Err(why) => return Some(Err(why.at_line(self.line_number))),
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::catalog::yale::{hr_1, YaleStar};
+    use crate::parse::reader::*;
+    use crate::parse::ParseErrorKind;
+
+    use std::io::Cursor;
+
+    /// Sample Yale catalog: a valid record, a removed object, a blank line, a malformed record and a truncated record.
+    fn yale() -> String {
+        let star = hr_1();
+        // NOTE: the catalog strips trailing spaces, so the line of a removed object ends before the positions.
+        let removed = star[..60].trim_end();
+        let mut malformed = star.clone();
+        malformed.replace_range(79..83, "?9.4");
+        let truncated = &star[..70];
+        format!("{}\n{}\n\n{}\n{}\n", star, removed, malformed, truncated)
+    }
+
+    #[test]
+    fn stream() {
+        let records: Vec<Result<YaleStar, ParseError>> =
+            CatalogReader::new(Cursor::new(yale())).collect();
+
+        // The removed object is skipped and the blank line is ignored
+        // NOTE: Yale lines are padded, so the truncated line can't be told from a removed object either.
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].as_ref().unwrap().HR, Some(1));
+        match &records[1] {
+            Err(ParseError::Field { line, field, .. }) => {
+                assert_eq!(*line, Some(4));
+                assert_eq!(*field, "RAs");
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn keep_invalid() {
+        let records: Vec<Result<YaleStar, ParseError>> = CatalogReader::new(Cursor::new(yale()))
+            .skip_invalid(false)
+            .collect();
+
+        assert_eq!(records.len(), 4);
+        assert!(!records[1].as_ref().unwrap().is_valid_parse());
+        assert!(records[2].is_err());
+        assert!(!records[3].as_ref().unwrap().is_valid_parse());
+    }
+
+    #[test]
+    fn unpadded() {
+        // Without padding, lines that end early are never skipped
+        let records: Vec<Result<YaleStar, ParseError>> =
+            CatalogReader::new(Cursor::new(yale())).pad(None).collect();
+
+        assert_eq!(records.len(), 4);
+        for (record, line) in [(&records[1], 2), (&records[3], 5)] {
+            match record {
+                Err(ParseError::Field {
+                    line: Some(number),
+                    kind,
+                    ..
+                }) => {
+                    assert_eq!(*number, line);
+                    assert_eq!(*kind, ParseErrorKind::Truncated);
+                }
+                other => panic!("unexpected result: {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn compressed() {
+        for data_file in [
+            "fixtures/Yale/bsc5.dat",
+            "fixtures/Yale/bsc5.dat.gz",
+            "fixtures/Yale/bsc5.dat.bz2",
+        ] {
+            let stars = CatalogReader::<_, YaleStar>::open(data_file)
+                .unwrap()
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+            assert_eq!(stars.len(), 1, "{}", data_file);
+            assert_eq!(stars[0].HR, Some(1), "{}", data_file);
+        }
+    }
+
+    #[test]
+    fn compressed_stream() {
+        let gzip: &[u8] = include_bytes!("../../fixtures/Yale/bsc5.dat.gz");
+        let stars: Vec<YaleStar> = CatalogReader::new(decompress(gzip).unwrap())
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(stars[0].Name, None);
+        assert_eq!(stars[0].SpType.as_deref(), Some("A1Vn"));
+    }
+
+    #[test]
+    fn decompress_short_reads() {
+        // One byte at a time, like a slow pipe
+        for data in [
+            &include_bytes!("../../fixtures/Yale/bsc5.dat")[..],
+            &include_bytes!("../../fixtures/Yale/bsc5.dat.gz")[..],
+            &include_bytes!("../../fixtures/Yale/bsc5.dat.bz2")[..],
+        ] {
+            let stars: Vec<YaleStar> =
+                CatalogReader::new(decompress(BufReader::with_capacity(1, data)).unwrap())
+                    .collect::<Result<_, _>>()
+                    .unwrap();
+            assert_eq!(stars.len(), 1);
+            assert_eq!(stars[0].HR, Some(1));
+        }
+
+        // Streams shorter than the magic pass through
+        let mut text = String::new();
+        decompress(BufReader::with_capacity(1, &b"B"[..]))
+            .unwrap()
+            .read_to_string(&mut text)
+            .unwrap();
+        assert_eq!(text, "B");
+    }
+}
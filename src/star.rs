@@ -2,7 +2,17 @@
 Star structures
  */
 
-use crate::coord::{Cartesian, Equitorial, Horizontal, Polar};
+use crate::angle::Angle;
+use crate::catalog::hipparcos::HipparcosStar;
+use crate::catalog::osbsc::OSBSCStar;
+use crate::catalog::yale::YaleStar;
+use crate::coord::{
+    Cartesian, ConstrainedAngle, Declination, Equitorial, Geographic, Horizontal, Polar,
+    RightAscension,
+};
+use crate::time::SiderealTime;
+
+use std::fmt;
 
 /**
 Kinds of star coordinates.
@@ -20,48 +30,395 @@ pub enum StarCoordinates {
 /**
 Generic star structure.
 
-> TODO: move this to a different crate and implement `from` traits for the catalog star structs in this crate.
+Catalog records can be converted into a `Star` with `From`, keeping the identifiers they share with other catalogs.
+Fields are `None` when the source catalog does not provide them.
+
+- Proper motions are annual, with the right ascension component multiplied by cos(declination).
+- Coordinates keep the equinox and epoch of the source catalog: J2000 for the Yale catalog and ICRS J1991.25 for Hipparcos and OSBSC.
  */
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct Star {
-    pub coordinates: StarCoordinates,
-    pub v_mag: f64,
-    pub name: String,
-    pub harvard: u32,
+    pub coordinates: Option<StarCoordinates>,
+    /// Visual magnitude (Johnson V)
+    pub v_mag: Option<f64>,
+    /// Johnson B-V color index
+    pub b_v: Option<f64>,
+    pub spectral_type: Option<String>,
+    /// Proper name
+    pub name: Option<String>,
+    /// Bayer designation (e.g. `Alp And` or `α And`)
+    pub bayer: Option<String>,
+    /// Flamsteed designation (e.g. `21 And`)
+    pub flamsteed: Option<String>,
+    /// Hipparcos catalog number (HIP)
+    pub hipparcos: Option<usize>,
+    /// Yale Bright Star / Harvard Revised catalog number (HR)
+    pub harvard: Option<usize>,
+    /// Henry Draper catalog number (HD)
+    pub henry_draper: Option<usize>,
+    /// Smithsonian Astrophysical Observatory catalog number (SAO)
+    pub sao: Option<usize>,
+    /// Fifth Fundamental Catalog number (FK5)
+    pub fk5: Option<usize>,
+    pub parallax: Option<Angle>,
+    /// Annual proper motion in right ascension, multiplied by cos(declination)
+    pub proper_motion_ra: Option<Angle>,
+    /// Annual proper motion in declination
+    pub proper_motion_dec: Option<Angle>,
+    /// Heliocentric radial velocity in km/s
+    pub radial_velocity: Option<f64>,
+}
+
+impl From<YaleStar> for Star {
+    fn from(star: YaleStar) -> Self {
+        Self {
+            coordinates: star.equatorial_j2000().map(StarCoordinates::Equitorial),
+            bayer: star.bayer(),
+            flamsteed: star.flamsteed(),
+            v_mag: star.Vmag,
+            b_v: star.B_V,
+            spectral_type: star.SpType,
+            name: None,
+            hipparcos: None,
+            harvard: star.HR,
+            henry_draper: star.HD,
+            sao: star.SAO,
+            fk5: star.FK5,
+            // NOTE: the Yale catalog gives parallaxes and proper motions in arcseconds.
+            parallax: star.Parallax.map(Angle::from_arcsec),
+            proper_motion_ra: star.pmRA.map(Angle::from_arcsec),
+            proper_motion_dec: star.pmDe.map(Angle::from_arcsec),
+            radial_velocity: star.RadVel.map(|v| v as f64),
+        }
+    }
+}
+
+impl From<HipparcosStar> for Star {
+    fn from(star: HipparcosStar) -> Self {
+        Self {
+            // NOTE: the sexagesimal position (H3/H4) is rounded, only use it when the degrees (H8/H9) are blank.
+            coordinates: Some(StarCoordinates::Equitorial(Equitorial {
+                right_ascension: star.RAdeg.unwrap_or(star.right_ascension),
+                declination: star.DEdeg.unwrap_or(star.declination),
+            })),
+            v_mag: star.Vmag,
+            b_v: star.B_V,
+            spectral_type: star.SpType,
+            name: None,
+            bayer: None,
+            flamsteed: None,
+            hipparcos: star.HIP,
+            harvard: None,
+            henry_draper: star.HD,
+            sao: None,
+            fk5: None,
+            parallax: star.Plx.map(Angle::from_mas),
+            proper_motion_ra: star.pmRA.map(Angle::from_mas),
+            proper_motion_dec: star.pmDE.map(Angle::from_mas),
+            radial_velocity: None,
+        }
+    }
+}
+
+impl From<OSBSCStar> for Star {
+    fn from(star: OSBSCStar) -> Self {
+        let coordinates = match (star.right_ascension_rad, star.declination_rad) {
+            (Some(ra), Some(dec)) => {
+                Declination::try_new(&Angle::from_rad(dec))
+                    .ok()
+                    .map(|declination| {
+                        StarCoordinates::Equitorial(Equitorial {
+                            right_ascension: RightAscension::new(&Angle::from_rad(ra)),
+                            declination,
+                        })
+                    })
+            }
+            _ => None,
+        };
+        Self {
+            coordinates,
+            v_mag: star.V_magnitude,
+            b_v: star.BV_magnitude,
+            spectral_type: star.spectral_type,
+            name: star.proper_name,
+            bayer: star.Bayer_id,
+            flamsteed: star.Flamsteed_id,
+            hipparcos: star.Hipparcos_id,
+            harvard: star.Yale_id,
+            henry_draper: star.HD_id,
+            sao: None,
+            fk5: None,
+            parallax: star.parallax.map(Angle::from_mas),
+            proper_motion_ra: star.proper_motion_ra.map(Angle::from_mas),
+            proper_motion_dec: star.proper_motion_dec.map(Angle::from_mas),
+            radial_velocity: star.radial_velocity,
+        }
+    }
+}
+
+/// Error returned when star coordinates cannot be converted without more context.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CoordinateConversionError {
+    /// Kind of the coordinates that were given
+    pub from: &'static str,
+    /// Kind of the coordinates that were requested
+    pub to: &'static str,
+}
+
+impl fmt::Display for CoordinateConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "could not convert {} star coordinates to {}",
+            self.from, self.to
+        )
+    }
+}
+
+impl std::error::Error for CoordinateConversionError {}
+
+impl StarCoordinates {
+    /// Name of the kind of coordinates
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Cartesian(_) => "Cartesian",
+            Self::Equitorial(_) => "Equitorial",
+            Self::Horizontal(_) => "Horizontal",
+            Self::Stereo(_) => "Stereo",
+        }
+    }
+
+    fn error(&self, to: &'static str) -> CoordinateConversionError {
+        CoordinateConversionError {
+            from: self.kind(),
+            to,
+        }
+    }
+
+    /// Equitorial coordinates, converting horizontal coordinates for a place and time.
+    pub fn to_equitorial(
+        &self,
+        geo: &Geographic,
+        sidereal_time: &impl SiderealTime,
+    ) -> Result<Equitorial, CoordinateConversionError> {
+        match self {
+            Self::Horizontal(coord) => Ok(Equitorial::from_horizontal(coord, geo, sidereal_time)),
+            other => Equitorial::try_from(*other),
+        }
+    }
+
+    /// Horizontal coordinates, converting equitorial (or cartesian) coordinates for a place and time.
+    pub fn to_horizontal(
+        &self,
+        geo: &Geographic,
+        sidereal_time: &impl SiderealTime,
+    ) -> Result<Horizontal, CoordinateConversionError> {
+        match self {
+            Self::Horizontal(coord) => Ok(*coord),
+            Self::Stereo(_) => Err(self.error("Horizontal")),
+            other => Ok(Horizontal::from_equitorial(
+                &Equitorial::try_from(*other)?,
+                geo,
+                sidereal_time,
+            )),
+        }
+    }
+
+    /// Stereographic projection of the coordinates as seen from a place and time.
+    pub fn to_stereo(
+        &self,
+        geo: &Geographic,
+        sidereal_time: &impl SiderealTime,
+    ) -> Result<Polar, CoordinateConversionError> {
+        match self {
+            Self::Stereo(coord) => Ok(*coord),
+            other => Ok(other.to_horizontal(geo, sidereal_time)?.stereo_project()),
+        }
+    }
 }
 
-impl From<StarCoordinates> for Cartesian {
-    fn from(coord: StarCoordinates) -> Self {
+/// Unit vector of equitorial coordinates.
+impl TryFrom<StarCoordinates> for Cartesian {
+    type Error = CoordinateConversionError;
+
+    fn try_from(coord: StarCoordinates) -> Result<Self, Self::Error> {
         match coord {
-            StarCoordinates::Cartesian(coord) => coord,
-            _ => panic!("Could not convert StarCoordinates to Cartesian!"),
+            StarCoordinates::Cartesian(coord) => Ok(coord),
+            StarCoordinates::Equitorial(coord) => Ok(coord.into()),
+            other => Err(other.error("Cartesian")),
         }
     }
 }
 
-impl From<StarCoordinates> for Equitorial {
-    fn from(coord: StarCoordinates) -> Self {
+/// Direction of cartesian coordinates.
+impl TryFrom<StarCoordinates> for Equitorial {
+    type Error = CoordinateConversionError;
+
+    fn try_from(coord: StarCoordinates) -> Result<Self, Self::Error> {
         match coord {
-            StarCoordinates::Equitorial(coord) => coord,
-            _ => panic!("Could not convert StarCoordinates to Equitorial!"),
+            StarCoordinates::Equitorial(coord) => Ok(coord),
+            StarCoordinates::Cartesian(coord) => Ok(coord.into()),
+            other => Err(other.error("Equitorial")),
         }
     }
 }
 
-impl From<StarCoordinates> for Horizontal {
-    fn from(coord: StarCoordinates) -> Self {
+/// Horizontal coordinates need a place and time, see [`StarCoordinates::to_horizontal`].
+impl TryFrom<StarCoordinates> for Horizontal {
+    type Error = CoordinateConversionError;
+
+    fn try_from(coord: StarCoordinates) -> Result<Self, Self::Error> {
         match coord {
-            StarCoordinates::Horizontal(coord) => coord,
-            _ => panic!("Could not convert StarCoordinates to Horizontal!"),
+            StarCoordinates::Horizontal(coord) => Ok(coord),
+            other => Err(other.error("Horizontal")),
         }
     }
 }
 
-impl From<StarCoordinates> for Polar {
-    fn from(coord: StarCoordinates) -> Self {
+/// Stereographic projection of horizontal coordinates.
+impl TryFrom<StarCoordinates> for Polar {
+    type Error = CoordinateConversionError;
+
+    fn try_from(coord: StarCoordinates) -> Result<Self, Self::Error> {
         match coord {
-            StarCoordinates::Stereo(coord) => coord,
-            _ => panic!("Could not convert StarCoordinates to Polar!"),
+            StarCoordinates::Stereo(coord) => Ok(coord),
+            StarCoordinates::Horizontal(coord) => Ok(coord.stereo_project()),
+            other => Err(other.error("Stereo")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::angle::Angle;
+    use crate::catalog::hipparcos::HipparcosStar;
+    use crate::catalog::osbsc::OSBSCStar;
+    use crate::catalog::yale::{hr_1, YaleStar};
+    use crate::coord::*;
+    use crate::star::*;
+    use crate::time::GMST;
+
+    #[test]
+    fn conversions() {
+        let eq = Equitorial {
+            right_ascension: RightAscension::new(&Angle::Degree(10.0)),
+            declination: Declination::new(&Angle::Degree(20.0)),
+        };
+        let coord = StarCoordinates::Equitorial(eq);
+
+        let v = Cartesian::try_from(coord).unwrap();
+        assert_float_absolute_eq!(v.z, Angle::Degree(20.0).sin());
+        let back = Equitorial::try_from(StarCoordinates::Cartesian(v)).unwrap();
+        assert_float_absolute_eq!(back.right_ascension.value().to_deg(), 10.0, 1e-9);
+
+        assert_eq!(
+            Horizontal::try_from(coord).unwrap_err(),
+            CoordinateConversionError {
+                from: "Equitorial",
+                to: "Horizontal"
+            }
+        );
+        assert_eq!(
+            Polar::try_from(coord).unwrap_err().to_string(),
+            "could not convert Equitorial star coordinates to Stereo"
+        );
+
+        let zenith = StarCoordinates::Horizontal(Horizontal {
+            altitude: Altitude::new(&Angle::Degree(90.0)),
+            azimuth: Azimuth::new(&Angle::Degree(0.0)),
+        });
+        assert_float_absolute_eq!(Polar::try_from(zenith).unwrap().radius, 0.0);
+        assert!(Equitorial::try_from(zenith).is_err());
+    }
+
+    #[test]
+    fn conversions_with_context() {
+        let geo = Geographic {
+            latitude: Latitude::new(&Angle::Degree(20.0)),
+            longitude: Longitude::new(&Angle::Degree(0.0)),
+        };
+        let sidereal_time = GMST(Angle::Degree(10.0));
+        let coord = StarCoordinates::Equitorial(Equitorial {
+            right_ascension: RightAscension::new(&Angle::Degree(10.0)),
+            declination: Declination::new(&Angle::Degree(20.0)),
+        });
+
+        // On the meridian at the zenith
+        let horiz = coord.to_horizontal(&geo, &sidereal_time).unwrap();
+        assert_float_absolute_eq!(horiz.altitude.value().to_deg(), 90.0, 1e-6);
+        let stereo = coord.to_stereo(&geo, &sidereal_time).unwrap();
+        assert_float_absolute_eq!(stereo.radius, 0.0, 1e-6);
+
+        let eq = StarCoordinates::Horizontal(horiz)
+            .to_equitorial(&geo, &sidereal_time)
+            .unwrap();
+        assert_float_absolute_eq!(eq.declination.value().to_deg(), 20.0, 1e-6);
+
+        // Projections cannot be undone
+        assert!(StarCoordinates::Stereo(stereo)
+            .to_horizontal(&geo, &sidereal_time)
+            .is_err());
+    }
+
+    #[test]
+    fn from_catalogs() {
+        let mut line = hr_1();
+        line.replace_range(4..14, " 21Alp And");
+        let yale = Star::from(YaleStar::try_from(line).unwrap());
+        assert_eq!(yale.harvard, Some(1));
+        assert_eq!(yale.henry_draper, Some(3));
+        assert_eq!(yale.sao, Some(36042));
+        assert_eq!(yale.hipparcos, None);
+        assert_eq!(yale.bayer.as_deref(), Some("Alp And"));
+        assert_eq!(yale.flamsteed.as_deref(), Some("21 And"));
+        assert_eq!(yale.spectral_type.as_deref(), Some("A1Vn"));
+        assert_float_absolute_eq!(yale.proper_motion_dec.unwrap().to_mas(), -18.0, 1e-9);
+        assert_eq!(yale.radial_velocity, Some(-18.0));
+        assert!(matches!(
+            yale.coordinates,
+            Some(StarCoordinates::Equitorial(_))
+        ));
+
+        let line = String::from("H|           1| |00 00 00.22|+01 05 20.4| 9.10| |H|000.00091185|+01.08901332| |   3.54|   -5.20|   -1.88|  1.32|  0.74|  1.39|  1.36|  0.81| 0.32|-0.07|-0.11|-0.24| 0.09|-0.01| 0.10|-0.01| 0.01| 0.34|  0| 0.74|     1| 9.643|0.020| 9.130|0.019| | 0.482|0.025|T|0.55|0.03|L| | 9.2043|0.0020|0.017| 87| | 9.17| 9.24|       | | | |          | |  | 1| | | |  |   |       |     |     |    |S| | |224700|B+00 5077 |          |          |0.66|F5          |S");
+        let hipparcos = Star::from(HipparcosStar::try_from(line.clone()).unwrap());
+        assert_eq!(hipparcos.hipparcos, Some(1));
+        assert_eq!(hipparcos.harvard, None);
+        assert_eq!(hipparcos.henry_draper, Some(224700));
+        assert_eq!(hipparcos.b_v, Some(0.482));
+        assert_float_absolute_eq!(hipparcos.parallax.unwrap().to_mas(), 3.54, 1e-9);
+        match hipparcos.coordinates {
+            Some(StarCoordinates::Equitorial(eq)) => {
+                assert_float_absolute_eq!(eq.right_ascension.value().to_deg(), 0.00091185, 1e-12);
+                assert_float_absolute_eq!(eq.declination.value().to_deg(), 1.08901332, 1e-12);
+            }
+            other => panic!("unexpected coordinates: {:?}", other),
+        }
+
+        // Falls back to the sexagesimal position without the degrees
+        let blank = line.replace("|000.00091185|+01.08901332|", "|            |            |");
+        let hipparcos = Star::from(HipparcosStar::try_from(blank).unwrap());
+        match hipparcos.coordinates {
+            Some(StarCoordinates::Equitorial(eq)) => {
+                assert_float_absolute_eq!(eq.right_ascension.value().to_hr(), 0.22 / 3600.0, 1e-12);
+                assert_float_absolute_eq!(
+                    eq.declination.value().to_deg(),
+                    1.0 + 5.0 / 60.0 + 20.4 / 3600.0,
+                    1e-12
+                );
+            }
+            other => panic!("unexpected coordinates: {:?}", other),
+        }
+
+        let osbsc = Star::from(OSBSCStar::try_from(String::from("    88  00_01_04.5982692  -48_48_35.492919  0.0046977187  -0.8518927495    5.50   -18.36    -5.82     8.0   0.26   0.29   0.48   0.46   0.38   0.7  5.71          G8III  0.911              224834 9081   τ Phe                        Phe BHHAAAAACAAAAACB-BB--BEE--H ")).unwrap());
+        assert_eq!(osbsc.hipparcos, Some(88));
+        assert_eq!(osbsc.harvard, Some(9081));
+        assert_eq!(osbsc.bayer.as_deref(), Some("τ Phe"));
+        assert_eq!(osbsc.radial_velocity, Some(8.0));
+        match osbsc.coordinates {
+            Some(StarCoordinates::Equitorial(eq)) => {
+                assert_float_absolute_eq!(eq.declination.value().to_rad(), -0.8518927495)
+            }
+            other => panic!("unexpected coordinates: {:?}", other),
         }
     }
 }
@@ -2,9 +2,22 @@
 Astonomical time types and utilities
  */
 
+pub mod delta_t;
+pub mod nutation;
+pub mod scale;
+pub mod sidereal;
+
+pub use sidereal::{SiderealTime, GAST, GMST, LAST, LMST};
+
 use crate::angle::{Angle, TWO_PI};
 
-use chrono::{DateTime, Datelike, Timelike, Utc};
+use auto_ops::*;
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, Timelike, Utc};
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::time::SystemTime;
 
 /**
 Earth Rotation Angle
@@ -13,59 +26,409 @@ Earth Rotation Angle
  */
 #[allow(clippy::excessive_precision)] // NOTE: actual equation has that much precision
 pub fn earth_rotation_angle(time_julian_ut1: JulianDate) -> Angle {
+    // NOTE: 1.00273781191135448 * days is split into days + 0.00273781191135448 * days so the whole days drop out (SOFA `iauEra00`).
+    let day_fraction = (time_julian_ut1.day.fract() + time_julian_ut1.fraction).rem_euclid(1.0);
     Angle::Radian(
-        TWO_PI * (0.7790572732640 + 1.00273781191135448 * (time_julian_ut1.0 - 2451545.0)),
+        TWO_PI
+            * (day_fraction + 0.7790572732640 + 0.00273781191135448 * time_julian_ut1.j2000_days()),
     )
 }
 
-/// Julian Date (J2000 epoch)
-#[derive(Debug)]
-pub struct JulianDate(
-    /// Seconds since J2000
-    pub f64,
+/// Julian Date of the J2000 epoch (2000 January 1, 12h TT)
+pub const J2000: f64 = 2451545.0;
+
+/// Julian Date of the Modified Julian Date epoch (1858 November 17, 0h)
+pub const MJD_EPOCH: f64 = 2400000.5;
+
+/// Days per Julian year
+pub const DAYS_PER_JULIAN_YEAR: f64 = 365.25;
+
+/// Days per Julian century
+pub const DAYS_PER_JULIAN_CENTURY: f64 = 36525.0;
+
+/**
+[Julian Date](https://en.wikipedia.org/wiki/Julian_day): days since noon, -4712 January 1 (Julian calendar).
+
+Stored in two parts like the [SOFA](http://www.iausofa.org/) library so that the fraction of the day keeps sub-nanosecond precision.
+A single `f64` only resolves tens of microseconds at current dates.
+The Julian Date is `day + fraction`; [`JulianDate::new`] keeps `day` whole and `fraction` in `[0, 1)`.
+ */
+#[derive(Debug, Copy, Clone)]
+pub struct JulianDate {
+    /// Whole days
+    pub day: f64,
+    /// Fraction of a day
+    pub fraction: f64,
+}
+
+impl JulianDate {
+    /// Julian Date from two parts that may be split anywhere, e.g. `new(2451545.0, 0.25)` or `new(2400000.5, 51544.75)`.
+    pub fn new(day: f64, fraction: f64) -> Self {
+        let whole = day.floor() + fraction.floor();
+        let rest = (day - day.floor()) + (fraction - fraction.floor());
+        Self {
+            day: whole + rest.floor(),
+            fraction: rest - rest.floor(),
+        }
+    }
+
+    /// Julian Date from a single number of days.
+    pub fn from_jd(jd: f64) -> Self {
+        Self::new(jd, 0.0)
+    }
+
+    /// Julian Date as a single number of days (loses precision, see [`JulianDate`]).
+    pub fn to_jd(&self) -> f64 {
+        self.day + self.fraction
+    }
+
+    /// Julian Date from a [Modified Julian Date](https://en.wikipedia.org/wiki/Julian_day#Variants).
+    pub fn from_mjd(mjd: f64) -> Self {
+        Self::new(MJD_EPOCH, mjd)
+    }
+
+    /// [Modified Julian Date](https://en.wikipedia.org/wiki/Julian_day#Variants): days since 1858 November 17, 0h.
+    pub fn to_mjd(&self) -> f64 {
+        (self.day - MJD_EPOCH) + self.fraction
+    }
+
+    /// Julian Date from days since the J2000 epoch.
+    pub fn from_j2000_days(days: f64) -> Self {
+        Self::new(J2000, days)
+    }
+
+    /// Days since the J2000 epoch.
+    pub fn j2000_days(&self) -> f64 {
+        (self.day - J2000) + self.fraction
+    }
+
+    /// Julian centuries since the J2000 epoch, the time argument `T` of precession and nutation models.
+    pub fn julian_centuries(&self) -> f64 {
+        self.j2000_days() / DAYS_PER_JULIAN_CENTURY
+    }
+
+    /// Julian Date a number of Julian centuries after the J2000 epoch.
+    pub fn from_julian_centuries(centuries: f64) -> Self {
+        Self::from_j2000_days(centuries * DAYS_PER_JULIAN_CENTURY)
+    }
+
+    /**
+    Dates from `start` (inclusive) to `end` (exclusive) every `step`.
+
+    Each date is offset from `start` rather than from the previous date, so rounding errors don't accumulate.
+    Empty if `step` isn't positive.
+     */
+    pub fn range(
+        start: JulianDate,
+        end: JulianDate,
+        step: Duration,
+    ) -> impl Iterator<Item = JulianDate> {
+        let positive = step > Duration::zero();
+        // NOTE: count in i64 and offset in i128 nanoseconds, an i32 counter overflows after 2^31 steps (~25 days of 1 ms).
+        let nanoseconds =
+            i128::from(step.num_seconds()) * 1_000_000_000 + i128::from(step.subsec_nanos());
+        let per_day = i128::from(NANOSECONDS_PER_DAY);
+        (0_i64..)
+            .map_while(move |i| {
+                let offset = nanoseconds.checked_mul(i128::from(i))?;
+                Some(JulianDate::new(
+                    start.day + offset.div_euclid(per_day) as f64,
+                    start.fraction + offset.rem_euclid(per_day) as f64 / per_day as f64,
+                ))
+            })
+            .take_while(move |date| positive && *date < end)
+    }
+}
+
+// NOTE: compare the normalised parts, the fields aren't necessarily normalised.
+impl PartialEq for JulianDate {
+    fn eq(&self, other: &Self) -> bool {
+        let (a, b) = (
+            JulianDate::new(self.day, self.fraction),
+            JulianDate::new(other.day, other.fraction),
+        );
+        a.day == b.day && a.fraction == b.fraction
+    }
+}
+
+impl PartialOrd for JulianDate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        let (a, b) = (
+            JulianDate::new(self.day, self.fraction),
+            JulianDate::new(other.day, other.fraction),
+        );
+        match a.day.partial_cmp(&b.day)? {
+            Ordering::Equal => a.fraction.partial_cmp(&b.fraction),
+            ordering => Some(ordering),
+        }
+    }
+}
+
+/// Hashes the normalised parts, consistent with `PartialEq`.
+impl Hash for JulianDate {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let date = JulianDate::new(self.day, self.fraction);
+        // NOTE: adding zero turns -0.0 into 0.0, which compare equal but have different bits.
+        (date.day + 0.0).to_bits().hash(state);
+        (date.fraction + 0.0).to_bits().hash(state);
+    }
+}
+
+/// Formats as `JD 2451545.250000`, `{:.N}` sets the number of decimals (6 by default, about 0.1 s).
+impl fmt::Display for JulianDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // NOTE: format the parts separately to keep the precision of the fraction.
+        let precision = f.precision().unwrap_or(6).min(15);
+        let scale = 10_f64.powi(precision as i32);
+        let date = JulianDate::new(self.day, self.fraction);
+        // NOTE: format the magnitude of negative dates, e.g. -0.25 is day -1 plus 0.75.
+        let (negative, mut day, fraction) = if date.day < 0.0 && date.fraction > 0.0 {
+            (true, -date.day - 1.0, 1.0 - date.fraction)
+        } else {
+            (date.day < 0.0, date.day.abs(), date.fraction)
+        };
+        let mut decimals = (fraction * scale).round();
+        if decimals >= scale {
+            day += 1.0;
+            decimals -= scale;
+        }
+        // NOTE: no sign for dates that round to zero.
+        let sign = if negative && (day > 0.0 || decimals > 0.0) {
+            "-"
+        } else {
+            ""
+        };
+        match precision {
+            0 => write!(f, "JD {}{:.0}", sign, day),
+            _ => write!(
+                f,
+                "JD {}{:.0}.{:0width$.0}",
+                sign,
+                day,
+                decimals,
+                width = precision
+            ),
+        }
+    }
+}
+
+impl_op_ex!(+|a: &JulianDate, b: &f64| -> JulianDate { JulianDate::new(a.day, a.fraction + b) });
+impl_op_ex!(-|a: &JulianDate, b: &f64| -> JulianDate { JulianDate::new(a.day, a.fraction - b) });
+impl_op_ex!(-|a: &JulianDate, b: &JulianDate| -> f64 {
+    // NOTE: subtract the parts separately so the whole days cancel exactly.
+    (a.day - b.day) + (a.fraction - b.fraction)
+});
+impl_op_ex!(+|a: &JulianDate, b: &Duration| -> JulianDate {
+    let seconds = b.num_seconds();
+    // NOTE: `subsec_nanos` has the sign of the duration.
+    let rest = seconds.rem_euclid(SECONDS_PER_DAY) as f64 + b.subsec_nanos() as f64 * 1e-9;
+    JulianDate::new(
+        a.day + seconds.div_euclid(SECONDS_PER_DAY) as f64,
+        a.fraction + rest / SECONDS_PER_DAY as f64,
+    )
+});
+impl_op_ex!(-|a: &JulianDate, b: &Duration| -> JulianDate { a + -*b });
+
+/**
+Calendar used to interpret a calendar date.
+
+Both calendars are proleptic, i.e. extended before their introduction. See [`JulianDate::from_calendar`].
+ */
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Calendar {
+    /// [Gregorian calendar](https://en.wikipedia.org/wiki/Gregorian_calendar), in use since 1582 October 15
+    Gregorian,
+    /// [Julian calendar](https://en.wikipedia.org/wiki/Julian_calendar), in use until 1582 October 4
+    Julian,
+}
+
+/// Error returned when a Julian Date is outside of the range of dates supported by `chrono`.
+#[derive(Debug, Copy, Clone)]
+pub struct DateRangeError(
+    /// Julian Date that could not be converted
+    pub JulianDate,
 );
 
+impl fmt::Display for DateRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Julian Date {} is outside of the supported date range",
+            self.0.to_jd()
+        )
+    }
+}
+
+impl std::error::Error for DateRangeError {}
+
+const SECONDS_PER_DAY: i64 = 86_400;
+const NANOSECONDS_PER_DAY: i64 = SECONDS_PER_DAY * 1_000_000_000;
+
+/**
+[Julian Day Number](https://en.wikipedia.org/wiki/Julian_day#Converting_Gregorian_calendar_date_to_Julian_Day_Number) of a proleptic Gregorian date (Fliegel & Van Flandern, 1968).
+
+Valid for any date, using floor division instead of the truncating division of the original algorithm.
+ */
+fn gregorian_to_day_number(year: i64, month: i64, day: i64) -> i64 {
+    // NOTE: January and February count as months 13 and 14 of the previous year.
+    let a = if month <= 2 { -1 } else { 0 };
+    (1461 * (year + 4800 + a)).div_euclid(4) + (367 * (month - 2 - 12 * a)).div_euclid(12)
+        - (3 * (year + 4900 + a).div_euclid(100)).div_euclid(4)
+        + day
+        - 32075
+}
+
+/// Proleptic Gregorian date of a Julian Day Number (Fliegel & Van Flandern, 1968).
+fn day_number_to_gregorian(day_number: i64) -> (i64, u32, u32) {
+    let l = day_number + 68569;
+    let n = (4 * l).div_euclid(146097);
+    let l = l - (146097 * n + 3).div_euclid(4);
+    let i = (4000 * (l + 1)).div_euclid(1461001);
+    let l = l - (1461 * i).div_euclid(4) + 31;
+    let j = (80 * l).div_euclid(2447);
+    let day = l - (2447 * j).div_euclid(80);
+    let l = j.div_euclid(11);
+    let month = j + 2 - 12 * l;
+    let year = 100 * (n - 49) + i + l;
+    (year, month as u32, day as u32)
+}
+
+impl JulianDate {
+    /**
+    Julian Date of a calendar date, with the time of day as a fraction of `day` (Meeus, Astronomical Algorithms, chapter 7).
+
+    Years are astronomical, i.e. 1 BC is year 0 and 2 BC is year -1.
+    Valid for all dates after -4712 January 1 (Julian Date 0).
+     */
+    pub fn from_calendar(year: i32, month: u32, day: f64, calendar: Calendar) -> Self {
+        let (year, month) = match month {
+            1 | 2 => (year as f64 - 1.0, month as f64 + 12.0),
+            _ => (year as f64, month as f64),
+        };
+        let b = match calendar {
+            Calendar::Gregorian => {
+                let a = (year / 100.0).floor();
+                2.0 - a + (a / 4.0).floor()
+            }
+            Calendar::Julian => 0.0,
+        };
+
+        Self::new(
+            (365.25 * (year + 4716.0)).floor() + (30.6001 * (month + 1.0)).floor() + b - 1524.5,
+            day,
+        )
+    }
+
+    /**
+    Calendar date `(year, month, day)` of the Julian Date, with the time of day as a fraction of `day` (Meeus, Astronomical Algorithms, chapter 7).
+
+    Years are astronomical, i.e. 1 BC is year 0. Not valid for negative Julian Dates.
+     */
+    pub fn to_calendar(&self, calendar: Calendar) -> (i32, u32, f64) {
+        // NOTE: calendar days start at midnight, half a day before Julian Days.
+        let JulianDate {
+            day: z,
+            fraction: f,
+        } = Self::new(self.day, self.fraction + 0.5);
+        let a = match calendar {
+            Calendar::Gregorian => {
+                let alpha = ((z - 1867216.25) / 36524.25).floor();
+                z + 1.0 + alpha - (alpha / 4.0).floor()
+            }
+            Calendar::Julian => z,
+        };
+        let b = a + 1524.0;
+        let c = ((b - 122.1) / 365.25).floor();
+        let d = (365.25 * c).floor();
+        let e = ((b - d) / 30.6001).floor();
+
+        let day = b - d - (30.6001 * e).floor() + f;
+        let month = if e < 14.0 { e - 1.0 } else { e - 13.0 };
+        let year = if month > 2.0 { c - 4716.0 } else { c - 4715.0 };
+        (year as i32, month as u32, day)
+    }
+}
+
+/// Julian Date of a (proleptic Gregorian) date and time, to the nanosecond.
 impl<T> From<DateTime<T>> for JulianDate
 where
     T: chrono::TimeZone,
     chrono::DateTime<chrono::Utc>: From<chrono::DateTime<T>>,
 {
     fn from(date: DateTime<T>) -> Self {
-        // https://stackoverflow.com/a/52431241
-
         // need UTC date
         let date: DateTime<Utc> = date.into();
-        assert!(
-            1801 <= date.year() && date.year() <= 2099,
-            "Datetime must be between year 1801 and 2099" // TODO: Find an algorithm that has a wider range.
-        );
 
-        Self(
-            // Note: all the integer divisions are truncating toward zero *by design*.
-            (367 * date.year() - ((7 * (date.year() + (date.month() as i32 + 9) / 12)) / 4)
-                + ((275 * date.month() as i32) / 9)) as f64
-                + date.day() as f64
-                + 1721013.5
-                + (date.hour() as f64
-                    + date.minute() as f64 / 60.0
-                    + date.second() as f64 / 3600.0)
-                    / 24.0
-                - (0.5_f64).copysign(100.0 * date.year() as f64 + date.month() as f64 - 190002.5)
-                + 0.5,
+        let day_number =
+            gregorian_to_day_number(date.year() as i64, date.month() as i64, date.day() as i64);
+        // NOTE: the nanoseconds exceed one second during a leap second.
+        let nanoseconds =
+            date.num_seconds_from_midnight() as i64 * 1_000_000_000 + date.nanosecond() as i64;
+
+        // NOTE: Julian Days start at noon.
+        Self::new(
+            day_number as f64,
+            nanoseconds as f64 / NANOSECONDS_PER_DAY as f64 - 0.5,
         )
     }
 }
 
-/// Greenwich Mean Sidereal Time
-pub struct GMST(
-    /// Hour
-    pub Angle,
-);
+/// Date and time of a Julian Date, rounded to the nanosecond.
+impl TryFrom<JulianDate> for DateTime<Utc> {
+    type Error = DateRangeError;
+
+    fn try_from(julian_date: JulianDate) -> Result<Self, Self::Error> {
+        let error = DateRangeError(julian_date);
+        let midnight = JulianDate::new(julian_date.day, julian_date.fraction + 0.5);
+        // NOTE: far beyond the years supported by chrono, but keeps the day number arithmetic from overflowing.
+        if !midnight.to_jd().is_finite() || midnight.day.abs() > 1e12 {
+            return Err(error);
+        }
+
+        let day_number = midnight.day as i64;
+        // NOTE: rounding can carry the time of day over to the next day.
+        let nanoseconds = (midnight.fraction * NANOSECONDS_PER_DAY as f64).round() as i64;
+        let (year, month, day) = day_number_to_gregorian(day_number);
 
-impl From<JulianDate> for GMST {
-    fn from(julian_date: JulianDate) -> Self {
-        // https://en.wikipedia.org/wiki/Sidereal_time
-        Self(Angle::Hour(earth_rotation_angle(julian_date).to_hr()))
+        let date = i32::try_from(year)
+            .ok()
+            .and_then(|year| NaiveDate::from_ymd_opt(year, month, day))
+            .ok_or(error)?;
+        let time = date
+            .and_hms_opt(0, 0, 0)
+            .and_then(|midnight| midnight.checked_add_signed(Duration::nanoseconds(nanoseconds)))
+            .ok_or(error)?;
+        Ok(time.and_utc())
+    }
+}
+
+/// Julian Date of a date and time in UTC.
+impl From<NaiveDateTime> for JulianDate {
+    fn from(date: NaiveDateTime) -> Self {
+        Self::from(date.and_utc())
+    }
+}
+
+/// Date and time in UTC of a Julian Date, rounded to the nanosecond.
+impl TryFrom<JulianDate> for NaiveDateTime {
+    type Error = DateRangeError;
+
+    fn try_from(julian_date: JulianDate) -> Result<Self, Self::Error> {
+        Ok(DateTime::<Utc>::try_from(julian_date)?.naive_utc())
+    }
+}
+
+impl From<SystemTime> for JulianDate {
+    fn from(time: SystemTime) -> Self {
+        Self::from(DateTime::<Utc>::from(time))
+    }
+}
+
+impl TryFrom<JulianDate> for SystemTime {
+    type Error = DateRangeError;
+
+    fn try_from(julian_date: JulianDate) -> Result<Self, Self::Error> {
+        Ok(DateTime::<Utc>::try_from(julian_date)?.into())
     }
 }
 
@@ -73,6 +436,8 @@ impl From<JulianDate> for GMST {
 mod tests {
     use chrono::TimeZone;
 
+    use std::collections::hash_map::DefaultHasher;
+
     use crate::time::*;
 
     #[test]
@@ -80,16 +445,164 @@ mod tests {
         // From: https://en.wikipedia.org/wiki/Epoch_(astronomy)#J2000
         // Definition of J2000 epoch
         assert_eq!(
-            JulianDate::from(Utc.with_ymd_and_hms(2000, 1, 1, 12, 0, 0).unwrap()).0,
+            JulianDate::from(Utc.with_ymd_and_hms(2000, 1, 1, 12, 0, 0).unwrap()).to_jd(),
             2451545.0
         );
 
         // From: https://en.wikipedia.org/wiki/Julian_day
         // 00:30:00.0 UT January 1, 2013, is 2_456_293.520_833
         assert_float_absolute_eq!(
-            JulianDate::from(Utc.with_ymd_and_hms(2013, 1, 1, 0, 30, 0).unwrap()).0,
+            JulianDate::from(Utc.with_ymd_and_hms(2013, 1, 1, 0, 30, 0).unwrap()).to_jd(),
             2_456_293.520_833,
             1e-6
         );
     }
+
+    #[test]
+    fn juliandate_calendar() {
+        // From: Meeus, Astronomical Algorithms, examples 7.a, 7.b and table on page 62
+        for (year, month, day, calendar, jd) in [
+            (1957, 10, 4.81, Calendar::Gregorian, 2436116.31),
+            (333, 1, 27.5, Calendar::Julian, 1842713.0),
+            (2000, 1, 1.5, Calendar::Gregorian, 2451545.0),
+            (1600, 12, 31.0, Calendar::Gregorian, 2305812.5),
+            (1582, 10, 15.0, Calendar::Gregorian, 2299160.5),
+            (1582, 10, 4.0, Calendar::Julian, 2299159.5),
+            (-1000, 7, 12.5, Calendar::Julian, 1356001.0),
+            (-1001, 8, 17.9, Calendar::Julian, 1355671.4),
+            (-4712, 1, 1.5, Calendar::Julian, 0.0),
+        ] {
+            let julian_date = JulianDate::from_calendar(year, month, day, calendar);
+            assert_float_absolute_eq!(julian_date.to_jd(), jd, 1e-9);
+
+            let (y, m, d) = julian_date.to_calendar(calendar);
+            assert_eq!((y, m), (year, month));
+            assert_float_absolute_eq!(d, day, 1e-6);
+        }
+
+        // From: Meeus, example 7.c
+        let (year, month, day) = JulianDate::from_jd(1507900.13).to_calendar(Calendar::Julian);
+        assert_eq!((year, month), (-584, 5));
+        assert_float_absolute_eq!(day, 28.63, 1e-6);
+    }
+
+    #[test]
+    fn juliandate_datetime() {
+        // Tycho's supernova was first seen on 1572 November 11 (Gregorian), November 1 in the Julian calendar then in use.
+        let tycho = JulianDate::from(Utc.with_ymd_and_hms(1572, 11, 11, 18, 0, 0).unwrap());
+        assert_float_absolute_eq!(
+            tycho.to_jd(),
+            JulianDate::from_calendar(1572, 11, 1.75, Calendar::Julian).to_jd(),
+            1e-9
+        );
+
+        for date in [
+            Utc.with_ymd_and_hms(-4000, 3, 1, 6, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(1572, 11, 11, 18, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2024, 2, 29, 23, 59, 59).unwrap()
+                + Duration::nanoseconds(123_456_789),
+            Utc.with_ymd_and_hms(3000, 12, 31, 12, 0, 0).unwrap(),
+        ] {
+            assert_eq!(
+                DateTime::<Utc>::try_from(JulianDate::from(date)).unwrap(),
+                date
+            );
+        }
+
+        assert!(DateTime::<Utc>::try_from(JulianDate::from_jd(f64::NAN)).is_err());
+        assert!(DateTime::<Utc>::try_from(JulianDate::from_jd(1e11)).is_err());
+    }
+
+    #[test]
+    fn juliandate_two_part() {
+        let j2000 = JulianDate::new(2400000.5, 51544.5);
+        assert_eq!((j2000.day, j2000.fraction), (2451545.0, 0.0));
+        assert_eq!(JulianDate::new(2451545.0, -0.25).fraction, 0.75);
+        assert_float_absolute_eq!(j2000.to_mjd(), 51544.5);
+        assert_float_absolute_eq!(JulianDate::from_mjd(51544.5).j2000_days(), 0.0);
+        assert_float_absolute_eq!(JulianDate::from_jd(2488070.0).julian_centuries(), 1.0);
+        assert_float_absolute_eq!(JulianDate::from_julian_centuries(-1.0).to_jd(), 2415020.0);
+
+        // A nanosecond is kept even though a single f64 Julian Date can't resolve it
+        let date = JulianDate::from(Utc.with_ymd_and_hms(2024, 6, 1, 3, 0, 0).unwrap());
+        let later = date + Duration::nanoseconds(1);
+        assert_eq!(date.to_jd(), later.to_jd());
+        assert_float_absolute_eq!((later - date) * 86400e9, 1.0, 1e-2);
+        assert_float_absolute_eq!((later - Duration::nanoseconds(1)) - date, 0.0, 1e-15);
+
+        let later = date + Duration::days(400) + Duration::hours(-6);
+        assert_float_absolute_eq!(later - date, 399.75, 1e-12);
+        assert_float_absolute_eq!((later - 399.75) - date, 0.0, 1e-12);
+        // 03:00 UTC is a fraction of 0.625, so half a day carries over to the next day
+        assert_eq!((date + 0.5).day, date.day + 1.0);
+        assert_float_absolute_eq!((date + 0.5).fraction, 0.125, 1e-12);
+    }
+
+    #[test]
+    fn juliandate_value() {
+        let date = JulianDate::new(2451545.0, 0.25);
+        assert_eq!(date.to_string(), "JD 2451545.250000");
+        assert_eq!(format!("{:.2}", date), "JD 2451545.25");
+        assert_eq!(format!("{:.0}", date), "JD 2451545");
+        assert_eq!(format!("{:.3}", date + 0.74999999), "JD 2451546.000");
+        assert_eq!(format!("{}", JulianDate::from_jd(-0.25)), "JD -0.250000");
+        assert_eq!(format!("{:.1}", JulianDate::from_jd(-1.75)), "JD -1.8");
+        assert_eq!(format!("{:.0}", JulianDate::from_jd(-0.75)), "JD -1");
+        assert_eq!(format!("{}", JulianDate::from_jd(-1.0)), "JD -1.000000");
+        assert_eq!(format!("{}", JulianDate::from_jd(-1e-9)), "JD 0.000000");
+        assert_eq!(format!("{}", JulianDate::from_jd(-0.0)), "JD 0.000000");
+
+        // Equal however the parts are split
+        let split = JulianDate {
+            day: 2400000.5,
+            fraction: 51544.75,
+        };
+        assert_eq!(date, split);
+        let hash = |date: &JulianDate| {
+            let mut hasher = DefaultHasher::new();
+            date.hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash(&date), hash(&split));
+        assert_eq!(
+            hash(&JulianDate::from_jd(0.0)),
+            hash(&JulianDate::from_jd(-0.0))
+        );
+        assert!(date < date + Duration::nanoseconds(1));
+        assert!(date - Duration::seconds(1) < split);
+
+        let dates: Vec<JulianDate> =
+            JulianDate::range(date, date + 1.0, Duration::hours(6)).collect();
+        assert_eq!(dates.len(), 4);
+        assert_eq!(dates[3], date + 0.75);
+        let seconds: Vec<JulianDate> =
+            JulianDate::range(date, date + 1.0, Duration::seconds(1)).collect();
+        assert_eq!(seconds.len(), 86_400);
+        assert_float_absolute_eq!(seconds[86_399] - date, 86_399.0 / 86_400.0, 1e-10);
+        assert_eq!(
+            JulianDate::range(date, date + 1.0, Duration::zero()).count(),
+            0
+        );
+        assert_eq!(
+            JulianDate::range(date + 1.0, date, Duration::hours(1)).count(),
+            0
+        );
+
+        let noon = NaiveDate::from_ymd_opt(2000, 1, 1)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap();
+        assert_eq!(JulianDate::from(noon).to_jd(), J2000);
+        assert_eq!(
+            NaiveDateTime::try_from(date).unwrap(),
+            noon + Duration::hours(6)
+        );
+
+        let epoch = JulianDate::from(SystemTime::UNIX_EPOCH);
+        assert_eq!(epoch.to_jd(), 2440587.5);
+        assert_eq!(
+            SystemTime::try_from(epoch + Duration::milliseconds(1500)).unwrap(),
+            SystemTime::UNIX_EPOCH + std::time::Duration::from_millis(1500)
+        );
+    }
 }
@@ -0,0 +1,331 @@
+/*!
+[ΔT](https://en.wikipedia.org/wiki/%CE%94T_(timekeeping)): the difference TT - UT1 caused by the irregular rotation of the Earth
+
+- [`EspenakMeeus`]: polynomial model valid for any date, accurate to a second or better since 1800
+- [`IersTable`]: measured values from an IERS Bulletin A (`finals2000A`) file, see <https://maia.usno.navy.mil/ser7/finals2000A.all>
+ */
+
+use super::scale::{tai_minus_utc, Tt, Utc, TT_MINUS_TAI};
+use super::{JulianDate, DAYS_PER_JULIAN_YEAR, J2000};
+use crate::parse::fields::{parse_fixed, required};
+use crate::parse::{CatalogRecord, ParseError, ValidParse};
+
+/// Provider of ΔT = TT - UT1.
+pub trait DeltaT {
+    /// ΔT in seconds at a date (TT or UT1, they differ by much less than ΔT changes).
+    fn delta_t(&self, date: &JulianDate) -> f64;
+}
+
+/**
+Polynomial expressions for ΔT by Espenak and Meeus.
+
+<https://eclipse.gsfc.nasa.gov/SEhelp/deltatpoly2004.html>
+
+> NOTE: predictions after 2005 have drifted by a few seconds from measured values, use an [`IersTable`] for recent dates.
+ */
+#[derive(Debug, Copy, Clone, Default)]
+pub struct EspenakMeeus;
+
+impl EspenakMeeus {
+    /// ΔT in seconds at a decimal year, e.g. `2000.5`.
+    pub fn at_year(year: f64) -> f64 {
+        // NOTE: long-term parabola used outside of -500 to 2150
+        let parabola = |y: f64| {
+            let u = (y - 1820.0) / 100.0;
+            -20.0 + 32.0 * u * u
+        };
+        let polynomial = |t: f64, coefficients: &[f64]| {
+            coefficients.iter().rev().fold(0.0, |sum, c| sum * t + c)
+        };
+
+        match year {
+            y if y < -500.0 => parabola(y),
+            y if y < 500.0 => polynomial(
+                y / 100.0,
+                &[
+                    10583.6,
+                    -1014.41,
+                    33.78311,
+                    -5.952053,
+                    -0.1798452,
+                    0.022174192,
+                    0.0090316521,
+                ],
+            ),
+            y if y < 1600.0 => polynomial(
+                (y - 1000.0) / 100.0,
+                &[
+                    1574.2,
+                    -556.01,
+                    71.23472,
+                    0.319781,
+                    -0.8503463,
+                    -0.005050998,
+                    0.0083572073,
+                ],
+            ),
+            y if y < 1700.0 => polynomial(y - 1600.0, &[120.0, -0.9808, -0.01532, 1.0 / 7129.0]),
+            y if y < 1800.0 => polynomial(
+                y - 1700.0,
+                &[8.83, 0.1603, -0.0059285, 0.00013336, -1.0 / 1174000.0],
+            ),
+            y if y < 1860.0 => polynomial(
+                y - 1800.0,
+                &[
+                    13.72,
+                    -0.332447,
+                    0.0068612,
+                    0.0041116,
+                    -0.00037436,
+                    0.0000121272,
+                    -0.0000001699,
+                    0.000000000875,
+                ],
+            ),
+            y if y < 1900.0 => polynomial(
+                y - 1860.0,
+                &[
+                    7.62,
+                    0.5737,
+                    -0.251754,
+                    0.01680668,
+                    -0.0004473624,
+                    1.0 / 233174.0,
+                ],
+            ),
+            y if y < 1920.0 => polynomial(
+                y - 1900.0,
+                &[-2.79, 1.494119, -0.0598939, 0.0061966, -0.000197],
+            ),
+            y if y < 1941.0 => polynomial(y - 1920.0, &[21.20, 0.84493, -0.076100, 0.0020936]),
+            y if y < 1961.0 => polynomial(y - 1950.0, &[29.07, 0.407, -1.0 / 233.0, 1.0 / 2547.0]),
+            y if y < 1986.0 => polynomial(y - 1975.0, &[45.45, 1.067, -1.0 / 260.0, -1.0 / 718.0]),
+            y if y < 2005.0 => polynomial(
+                y - 2000.0,
+                &[
+                    63.86,
+                    0.3345,
+                    -0.060374,
+                    0.0017275,
+                    0.000651814,
+                    0.00002373599,
+                ],
+            ),
+            y if y < 2050.0 => polynomial(y - 2000.0, &[62.92, 0.32217, 0.005589]),
+            y if y < 2150.0 => parabola(y) - 0.5628 * (2150.0 - y),
+            y => parabola(y),
+        }
+    }
+}
+
+impl DeltaT for EspenakMeeus {
+    fn delta_t(&self, date: &JulianDate) -> f64 {
+        Self::at_year(2000.0 + (date.day - J2000 + date.fraction) / DAYS_PER_JULIAN_YEAR)
+    }
+}
+
+/**
+Earth orientation record of an IERS Bulletin A file (`finals2000A.all`, `finals2000A.daily`, ...)
+
+Only the fields needed for ΔT are parsed.
+
+```text
+--------------------------------------------------------------------------------
+   Bytes Format  Units   Label    Explanations
+--------------------------------------------------------------------------------
+   8- 15  F8.2   d       MJD      Modified Julian Date (UTC)
+      58  A1     ---     Flag     [IP] IERS (I) or Prediction (P) for UT1-UTC
+  59- 68  F10.7  s       UT1-UTC  UT1-UTC (Bulletin A)
+--------------------------------------------------------------------------------
+```
+
+Records past the predictions have no UT1-UTC and are skipped.
+*/
+#[derive(Debug, Clone)]
+pub struct IersRecord {
+    /// Modified Julian Date (UTC)
+    pub mjd: Option<f64>,
+
+    /// \[IP\] IERS (I) or Prediction (P) for UT1-UTC
+    pub flag: Option<String>,
+
+    /// UT1-UTC in seconds
+    pub dut1: Option<f64>,
+}
+
+impl TryFrom<String> for IersRecord {
+    type Error = ParseError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        let record = Self {
+            mjd: parse_fixed(&s, 7..15, "MJD")?,
+            flag: parse_fixed(&s, 57..58, "Flag")?,
+            dut1: parse_fixed(&s, 58..68, "UT1-UTC")?,
+        };
+        required(&s, &record.mjd, 7..15, "MJD")?;
+        // NOTE: lines past the predictions stop after the date, those fail `ValidParse` instead.
+        Ok(record)
+    }
+}
+
+impl ValidParse for IersRecord {
+    fn is_valid_parse(&self) -> bool {
+        self.mjd.is_some() && self.dut1.is_some()
+    }
+}
+
+impl CatalogRecord for IersRecord {}
+
+/**
+Table of UT1-UTC from an IERS Bulletin A file, linearly interpolated between days.
+
+Falls back to another provider (by default [`EspenakMeeus`]) outside of the table.
+
+```no_run
+use starstuff_types::parse::CatalogReader;
+use starstuff_types::time::delta_t::{IersRecord, IersTable};
+
+let table: IersTable = CatalogReader::<_, IersRecord>::open("finals2000A.all")
+    .unwrap()
+    .collect::<Result<_, _>>()
+    .unwrap();
+```
+*/
+#[derive(Debug, Clone, Default)]
+pub struct IersTable<F = EspenakMeeus> {
+    /// `(MJD, UT1-UTC)` sorted by date
+    dut1: Vec<(f64, f64)>,
+    fallback: F,
+}
+
+impl<F: DeltaT> IersTable<F> {
+    /// Use another provider outside of the table.
+    pub fn with_fallback<G: DeltaT>(self, fallback: G) -> IersTable<G> {
+        IersTable {
+            dut1: self.dut1,
+            fallback,
+        }
+    }
+
+    /// UT1-UTC in seconds at a UTC date, `None` outside of the table.
+    pub fn dut1(&self, utc: &JulianDate) -> Option<f64> {
+        let mjd = utc.to_mjd();
+        let next = self.dut1.partition_point(|(day, _)| *day <= mjd);
+        if next == 0 {
+            return None;
+        }
+        let (day, dut1) = self.dut1[next - 1];
+        match self.dut1.get(next) {
+            Some(&(next_day, next_dut1)) => {
+                // NOTE: UT1-UTC jumps by a second at a leap second, interpolate without the jump.
+                let leap = (next_dut1 - dut1).round();
+                Some(dut1 + (next_dut1 - leap - dut1) * (mjd - day) / (next_day - day))
+            }
+            None if mjd == day => Some(dut1),
+            None => None,
+        }
+    }
+}
+
+impl<F: DeltaT> DeltaT for IersTable<F> {
+    fn delta_t(&self, date: &JulianDate) -> f64 {
+        // NOTE: leap seconds and UT1-UTC must be looked up at the same UTC date so that their jumps cancel out.
+        let utc = Utc::from(Tt(*date)).0;
+        match self.dut1(&utc) {
+            Some(dut1) => TT_MINUS_TAI + tai_minus_utc(&utc) - dut1,
+            None => self.fallback.delta_t(date),
+        }
+    }
+}
+
+impl FromIterator<IersRecord> for IersTable {
+    fn from_iter<I: IntoIterator<Item = IersRecord>>(iter: I) -> Self {
+        let mut dut1: Vec<(f64, f64)> = iter
+            .into_iter()
+            .filter_map(|record| Some((record.mjd?, record.dut1?)))
+            .collect();
+        dut1.sort_by(|a, b| a.0.total_cmp(&b.0));
+        Self {
+            dut1,
+            fallback: EspenakMeeus,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::CatalogReader;
+    use crate::time::delta_t::*;
+
+    use std::io::Cursor;
+
+    #[test]
+    fn espenak_meeus() {
+        assert_float_absolute_eq!(EspenakMeeus::at_year(2000.0), 63.86);
+        assert_float_absolute_eq!(EspenakMeeus::at_year(1900.0), -2.79);
+        assert_float_absolute_eq!(EspenakMeeus::at_year(0.0), 10583.6);
+        assert_float_absolute_eq!(
+            EspenakMeeus::at_year(1620.0),
+            120.0 - 19.616 - 6.128 + 8000.0 / 7129.0
+        );
+        assert_float_absolute_eq!(EspenakMeeus::at_year(-1000.0), -20.0 + 32.0 * 28.2 * 28.2);
+
+        // Segments join up to a few seconds at most
+        for year in [
+            500.0, 1600.0, 1700.0, 1800.0, 1860.0, 1900.0, 1920.0, 1941.0, 1961.0, 1986.0, 2005.0,
+            2050.0, 2150.0,
+        ] {
+            let step = EspenakMeeus::at_year(year) - EspenakMeeus::at_year(year - 1e-9);
+            assert!(step.abs() < 3.0, "{}: {}", year, step);
+        }
+
+        let j2000 = JulianDate::from_jd(J2000);
+        assert_float_absolute_eq!(EspenakMeeus.delta_t(&j2000), 63.86);
+    }
+
+    /// Lines in the `finals2000A.all` format around the 2016 leap second, with a line past the predictions.
+    fn finals() -> String {
+        [
+            "161230 57752.00 I  0.014855 0.000029  0.284462 0.000027  I-0.4056010 0.0000086  0.9005 0.0061  I    -0.185     0.066    -0.270     0.047   0.014823   0.284464  -0.4056000    -0.179    -0.267",
+            "161231 57753.00 I  0.014037 0.000023  0.285018 0.000025  I-0.4065180 0.0000082  0.9331 0.0060  I    -0.186     0.066    -0.271     0.047   0.014004   0.285021  -0.4065200    -0.180    -0.268",
+            "17 1 1 57754.00 I  0.013108 0.000026  0.285609 0.000028  I 0.5925080 0.0000085  0.9458 0.0058  I    -0.191     0.066    -0.273     0.047   0.013074   0.285609   0.5925100    -0.185    -0.270",
+            "17 1 2 57755.00 I  0.012203 0.000026  0.286167 0.000028  I 0.5915830 0.0000085  0.9250 0.0058  I    -0.195     0.066    -0.275     0.047   0.012171   0.286168   0.5915800    -0.189    -0.272",
+            "2512 1 61010.00",
+        ]
+        .join("\n")
+    }
+
+    #[test]
+    fn iers_table() {
+        let table: IersTable = CatalogReader::<_, IersRecord>::new(Cursor::new(finals()))
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        let noon = JulianDate::from_mjd(57752.5);
+        assert_float_absolute_eq!(table.dut1(&noon).unwrap(), -0.4060595, 1e-9);
+        assert_float_absolute_eq!(
+            table.delta_t(&Tt::from(Utc(noon)).0),
+            32.184 + 36.0 + 0.4060595,
+            1e-9
+        );
+
+        // UT1-UTC jumps by a second at the leap second, ΔT doesn't
+        let before = JulianDate::from_mjd(57753.999);
+        assert_float_absolute_eq!(
+            table.dut1(&before).unwrap(),
+            -0.4065180 + (0.5925080 - 1.0 + 0.4065180) * 0.999,
+            1e-9
+        );
+        let leap = Tt::from(Utc(JulianDate::from_mjd(57754.0))).0;
+        for second in -120..120 {
+            let tt = leap + second as f64 / 86400.0;
+            let step = table.delta_t(&(tt + 1.0 / 86400.0)) - table.delta_t(&tt);
+            assert!(step.abs() < 1e-4, "{}: {}", second, step);
+        }
+
+        // Outside of the table
+        let later = JulianDate::from_mjd(57757.0);
+        assert!(table.dut1(&later).is_none());
+        assert_eq!(table.delta_t(&later), EspenakMeeus.delta_t(&later));
+    }
+}
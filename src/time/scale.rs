@@ -0,0 +1,256 @@
+/*!
+Astronomical [time scales](https://en.wikipedia.org/wiki/Time_standard)
+
+Each scale wraps a [`JulianDate`] so that calculations state which scale they expect:
+
+- [`Utc`]: Coordinated Universal Time, civil time kept within a second of UT1 by leap seconds
+- [`Tai`]: International Atomic Time, `TAI = UTC + leap seconds`
+- [`Tt`]: Terrestrial Time, `TT = TAI + 32.184 s`, the time argument of geocentric ephemerides
+- [`Tdb`]: Barycentric Dynamical Time, within 2 ms of TT
+- [`Ut1`]: Universal Time, the rotation angle of the Earth, `UT1 = TT - ΔT`
+
+Conversions to and from UT1 need a [`DeltaT`] provider because the rotation of the Earth is irregular.
+ */
+
+use super::delta_t::DeltaT;
+use super::JulianDate;
+use crate::angle::Angle;
+
+use chrono::DateTime;
+
+const SECONDS_PER_DAY: f64 = 86400.0;
+
+/// TT - TAI in seconds
+pub const TT_MINUS_TAI: f64 = 32.184;
+
+/**
+Leap seconds: Modified Julian Date (UTC) from which TAI - UTC applies, and TAI - UTC in seconds.
+
+From the [IERS Bulletin C](https://hpiers.obspm.fr/iers/bul/bulc/Leap_Second.dat).
+ */
+pub const LEAP_SECONDS: [(f64, f64); 28] = [
+    (41317.0, 10.0), // 1972 January 1
+    (41499.0, 11.0), // 1972 July 1
+    (41683.0, 12.0), // 1973 January 1
+    (42048.0, 13.0), // 1974 January 1
+    (42413.0, 14.0), // 1975 January 1
+    (42778.0, 15.0), // 1976 January 1
+    (43144.0, 16.0), // 1977 January 1
+    (43509.0, 17.0), // 1978 January 1
+    (43874.0, 18.0), // 1979 January 1
+    (44239.0, 19.0), // 1980 January 1
+    (44786.0, 20.0), // 1981 July 1
+    (45151.0, 21.0), // 1982 July 1
+    (45516.0, 22.0), // 1983 July 1
+    (46247.0, 23.0), // 1985 July 1
+    (47161.0, 24.0), // 1988 January 1
+    (47892.0, 25.0), // 1990 January 1
+    (48257.0, 26.0), // 1991 January 1
+    (48804.0, 27.0), // 1992 July 1
+    (49169.0, 28.0), // 1993 July 1
+    (49534.0, 29.0), // 1994 July 1
+    (50083.0, 30.0), // 1996 January 1
+    (50630.0, 31.0), // 1997 July 1
+    (51179.0, 32.0), // 1999 January 1
+    (53736.0, 33.0), // 2006 January 1
+    (54832.0, 34.0), // 2009 January 1
+    (56109.0, 35.0), // 2012 July 1
+    (57204.0, 36.0), // 2015 July 1
+    (57754.0, 37.0), // 2017 January 1
+];
+
+/**
+TAI - UTC in seconds at a UTC date.
+
+> NOTE: UTC before 1972 had variable length seconds, which aren't modelled: the 1972 offset of 10 s is used.
+ */
+pub fn tai_minus_utc(utc: &JulianDate) -> f64 {
+    let mjd = utc.to_mjd();
+    LEAP_SECONDS
+        .iter()
+        .rev()
+        .find(|(start, _)| mjd >= *start)
+        .map_or(LEAP_SECONDS[0].1, |(_, seconds)| *seconds)
+}
+
+/// TAI - UTC in seconds at a TAI date.
+fn tai_minus_utc_at_tai(tai: &JulianDate) -> f64 {
+    let mjd = tai.to_mjd();
+    // NOTE: UTC can't represent the leap second itself, split it in the middle so rounding can't pick the wrong side of midnight.
+    LEAP_SECONDS
+        .iter()
+        .rev()
+        .find(|(start, seconds)| mjd >= start + (seconds - 0.5) / SECONDS_PER_DAY)
+        .map_or(LEAP_SECONDS[0].1, |(_, seconds)| *seconds)
+}
+
+/// Coordinated Universal Time
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub struct Utc(pub JulianDate);
+
+/// International Atomic Time
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub struct Tai(pub JulianDate);
+
+/// Terrestrial Time
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub struct Tt(pub JulianDate);
+
+/// Barycentric Dynamical Time
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub struct Tdb(pub JulianDate);
+
+/// Universal Time (UT1)
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub struct Ut1(pub JulianDate);
+
+impl<T> From<DateTime<T>> for Utc
+where
+    T: chrono::TimeZone,
+    chrono::DateTime<chrono::Utc>: From<chrono::DateTime<T>>,
+{
+    fn from(date: DateTime<T>) -> Self {
+        Self(JulianDate::from(date))
+    }
+}
+
+impl From<Utc> for Tai {
+    fn from(utc: Utc) -> Self {
+        Self(utc.0 + tai_minus_utc(&utc.0) / SECONDS_PER_DAY)
+    }
+}
+
+impl From<Tai> for Utc {
+    fn from(tai: Tai) -> Self {
+        Self(tai.0 - tai_minus_utc_at_tai(&tai.0) / SECONDS_PER_DAY)
+    }
+}
+
+impl From<Tai> for Tt {
+    fn from(tai: Tai) -> Self {
+        Self(tai.0 + TT_MINUS_TAI / SECONDS_PER_DAY)
+    }
+}
+
+impl From<Tt> for Tai {
+    fn from(tt: Tt) -> Self {
+        Self(tt.0 - TT_MINUS_TAI / SECONDS_PER_DAY)
+    }
+}
+
+impl From<Utc> for Tt {
+    fn from(utc: Utc) -> Self {
+        Tai::from(utc).into()
+    }
+}
+
+impl From<Tt> for Utc {
+    fn from(tt: Tt) -> Self {
+        Tai::from(tt).into()
+    }
+}
+
+/**
+TDB - TT in seconds, periodic terms of the Earth's orbit to within 30 μs.
+
+<https://en.wikipedia.org/wiki/Barycentric_Dynamical_Time>
+ */
+fn tdb_minus_tt(tt: &JulianDate) -> f64 {
+    // Mean anomaly of the Earth
+    let g = Angle::from_deg(357.53 + 0.98560028 * tt.j2000_days());
+    0.001657 * g.sin() + 0.000014 * (g * 2.0).sin()
+}
+
+impl From<Tt> for Tdb {
+    fn from(tt: Tt) -> Self {
+        Self(tt.0 + tdb_minus_tt(&tt.0) / SECONDS_PER_DAY)
+    }
+}
+
+impl From<Tdb> for Tt {
+    fn from(tdb: Tdb) -> Self {
+        // NOTE: TDB and TT differ by less than 2 ms, so the TDB date is close enough for the periodic terms.
+        Self(tdb.0 - tdb_minus_tt(&tdb.0) / SECONDS_PER_DAY)
+    }
+}
+
+impl Tt {
+    /// UT1 from TT and ΔT.
+    pub fn to_ut1(&self, delta_t: &impl DeltaT) -> Ut1 {
+        Ut1(self.0 - delta_t.delta_t(&self.0) / SECONDS_PER_DAY)
+    }
+}
+
+impl Ut1 {
+    /// TT from UT1 and ΔT.
+    pub fn to_tt(&self, delta_t: &impl DeltaT) -> Tt {
+        Tt(self.0 + delta_t.delta_t(&self.0) / SECONDS_PER_DAY)
+    }
+}
+
+impl Utc {
+    /// UT1 from UTC and ΔT, i.e. `UTC + DUT1`.
+    pub fn to_ut1(&self, delta_t: &impl DeltaT) -> Ut1 {
+        Tt::from(*self).to_ut1(delta_t)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::time::delta_t::EspenakMeeus;
+    use crate::time::scale::*;
+    use crate::time::Calendar;
+
+    use chrono::TimeZone;
+
+    #[test]
+    fn leap_seconds() {
+        // Leap seconds are inserted at the end of June or December
+        for (mjd, _) in LEAP_SECONDS {
+            let (_, month, day) = JulianDate::from_mjd(mjd).to_calendar(Calendar::Gregorian);
+            assert!(matches!((month, day as u32), (1, 1) | (7, 1)), "{}", mjd);
+        }
+
+        let before = Utc::from(
+            chrono::Utc
+                .with_ymd_and_hms(2016, 12, 31, 23, 59, 59)
+                .unwrap(),
+        );
+        let after = Utc::from(chrono::Utc.with_ymd_and_hms(2017, 1, 1, 0, 0, 0).unwrap());
+        assert_eq!(tai_minus_utc(&before.0), 36.0);
+        assert_eq!(tai_minus_utc(&after.0), 37.0);
+        assert_eq!(tai_minus_utc(&JulianDate::from_mjd(30000.0)), 10.0);
+
+        // The leap second makes consecutive UTC seconds two TAI seconds apart
+        let (before, after) = (Tai::from(before), Tai::from(after));
+        assert_float_absolute_eq!((after.0 - before.0) * SECONDS_PER_DAY, 2.0, 1e-6);
+        for tai in [before, after] {
+            let back = Tai::from(Utc::from(tai));
+            assert_float_absolute_eq!((back.0 - tai.0) * SECONDS_PER_DAY, 0.0, 1e-6);
+        }
+    }
+
+    #[test]
+    fn scales() {
+        let utc = Utc::from(chrono::Utc.with_ymd_and_hms(2020, 6, 1, 0, 0, 0).unwrap());
+        let tt = Tt::from(utc);
+        assert_float_absolute_eq!((tt.0 - utc.0) * SECONDS_PER_DAY, 69.184, 1e-6);
+        assert_float_absolute_eq!((Utc::from(tt).0 - utc.0) * SECONDS_PER_DAY, 0.0, 1e-6);
+
+        // TDB - TT is periodic with an amplitude of 1.657 ms
+        let tdb = Tdb::from(tt);
+        let offset = (tdb.0 - tt.0) * SECONDS_PER_DAY;
+        assert!(offset.abs() < 0.00168, "{}", offset);
+        assert_float_absolute_eq!((Tt::from(tdb).0 - tt.0) * SECONDS_PER_DAY, 0.0, 1e-9);
+
+        let ut1 = utc.to_ut1(&EspenakMeeus);
+        let delta_t = (tt.0 - ut1.0) * SECONDS_PER_DAY;
+        assert_float_absolute_eq!(delta_t, EspenakMeeus.delta_t(&tt.0), 1e-6);
+        // NOTE: ΔT is looked up at the UT1 date on the way back, about a minute earlier.
+        assert_float_absolute_eq!(
+            (ut1.to_tt(&EspenakMeeus).0 - tt.0) * SECONDS_PER_DAY,
+            0.0,
+            1e-5
+        );
+    }
+}
@@ -0,0 +1,357 @@
+/*!
+[Sidereal time](https://en.wikipedia.org/wiki/Sidereal_time)
+
+- [`GMST`]: Greenwich Mean Sidereal Time, the hour angle of the mean equinox at Greenwich
+- [`GAST`]: Greenwich Apparent Sidereal Time, the hour angle of the true equinox (GMST + equation of the equinoxes)
+- [`LMST`] and [`LAST`]: the same at the longitude of an observer
+
+Any of them can be used to convert between equitorial and horizontal coordinates through [`SiderealTime`].
+ */
+
+use super::nutation::nutation;
+use super::{earth_rotation_angle, JulianDate, DAYS_PER_JULIAN_CENTURY, J2000};
+use crate::angle::{Angle, HourMinSec, TWO_PI};
+use crate::coord::{mean_obliquity, ConstrainedAngle, Longitude, ObliquityModel};
+
+use std::fmt;
+
+/// Sidereal time that gives the right ascension on the meridian of an observer.
+pub trait SiderealTime {
+    /// Local sidereal time at a longitude (east longitudes are positive).
+    fn at_longitude(&self, longitude: &Longitude) -> Angle;
+}
+
+/// Greenwich Mean Sidereal Time
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub struct GMST(
+    /// Hour
+    pub Angle,
+);
+
+/// Greenwich Apparent Sidereal Time
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub struct GAST(
+    /// Hour
+    pub Angle,
+);
+
+/// Local Mean Sidereal Time
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub struct LMST(
+    /// Hour
+    pub Angle,
+);
+
+/// Local Apparent Sidereal Time
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub struct LAST(
+    /// Hour
+    pub Angle,
+);
+
+impl GMST {
+    /**
+    GMST of the IAU 1982 model (Aoki et al.) from a UT1 julian date.
+
+    Consistent with the FK5 frame and the IAU 1976 precession (see SOFA `iauGmst82`).
+     */
+    #[allow(clippy::excessive_precision)] // NOTE: actual equation has that much precision
+    pub fn iau1982(ut1: &JulianDate) -> Self {
+        // Coefficients of the polynomial in seconds, the constant term adjusted for Julian Days starting at noon.
+        let (a, b, c, d) = (24110.54841 - 43200.0, 8640184.812866, 0.093104, -6.2e-6);
+        let t = (ut1.day - J2000 + ut1.fraction) / DAYS_PER_JULIAN_CENTURY;
+        let day_fraction = (ut1.day.fract() + ut1.fraction).rem_euclid(1.0);
+        let seconds = a + t * (b + t * (c + t * d)) + day_fraction * 86400.0;
+        Self(Angle::from_rad(seconds * TWO_PI / 86400.0).normalize_0_24h())
+    }
+
+    /**
+    GMST of the IAU 2000 model (Capitaine et al. 2003) from UT1 and TT julian dates.
+
+    Consistent with the IAU 2000 precession-nutation (see SOFA `iauGmst00`).
+     */
+    pub fn iau2000(ut1: &JulianDate, tt: &JulianDate) -> Self {
+        let t = tt.julian_centuries();
+        let precession =
+            0.014506 + t * (4612.15739966 + t * (1.39667721 + t * (-0.00009344 + t * 0.00001882)));
+        Self((earth_rotation_angle(*ut1) + Angle::from_arcsec(precession)).normalize_0_24h())
+    }
+
+    /**
+    GMST of the IAU 2006 model (Capitaine et al. 2005) from UT1 and TT julian dates.
+
+    The Earth rotation angle plus the accumulated precession in right ascension (see SOFA `iauGmst06`).
+     */
+    pub fn iau2006(ut1: &JulianDate, tt: &JulianDate) -> Self {
+        let t = tt.julian_centuries();
+        let precession = 0.014506
+            + t * (4612.156534
+                + t * (1.3915817 + t * (-0.00000044 + t * (-0.000029956 + t * -0.0000000368))));
+        Self((earth_rotation_angle(*ut1) + Angle::from_arcsec(precession)).normalize_0_24h())
+    }
+
+    /// Local Mean Sidereal Time at a longitude (east longitudes are positive).
+    pub fn to_local(&self, longitude: &Longitude) -> LMST {
+        LMST(self.at_longitude(longitude))
+    }
+}
+
+/**
+GMST of the IAU 2006 model from a UT1 julian date.
+
+> NOTE: TT is taken equal to UT1, which changes GMST by less than 0.1 mas.
+ */
+impl From<JulianDate> for GMST {
+    fn from(ut1: JulianDate) -> Self {
+        Self::iau2006(&ut1, &ut1)
+    }
+}
+
+/**
+Complementary terms of the equation of the equinoxes: multiples of l, l', F, D, Ω, L<sub>Ve</sub>, L<sub>E</sub> and p<sub>A</sub>,
+then the sine and cosine coefficients in μas (IERS Conventions 2003, see SOFA `iauEect00`).
+ */
+#[rustfmt::skip]
+const COMPLEMENTARY_TERMS: [[f64; 10]; 33] = [
+    [ 0.0,  0.0,  0.0,  0.0,  1.0,  0.0,   0.0,  0.0, 2640.96, -0.39],
+    [ 0.0,  0.0,  0.0,  0.0,  2.0,  0.0,   0.0,  0.0,   63.52, -0.02],
+    [ 0.0,  0.0,  2.0, -2.0,  3.0,  0.0,   0.0,  0.0,   11.75,  0.01],
+    [ 0.0,  0.0,  2.0, -2.0,  1.0,  0.0,   0.0,  0.0,   11.21,  0.01],
+    [ 0.0,  0.0,  2.0, -2.0,  2.0,  0.0,   0.0,  0.0,   -4.55,  0.00],
+    [ 0.0,  0.0,  2.0,  0.0,  3.0,  0.0,   0.0,  0.0,    2.02,  0.00],
+    [ 0.0,  0.0,  2.0,  0.0,  1.0,  0.0,   0.0,  0.0,    1.98,  0.00],
+    [ 0.0,  0.0,  0.0,  0.0,  3.0,  0.0,   0.0,  0.0,   -1.72,  0.00],
+    [ 0.0,  1.0,  0.0,  0.0,  1.0,  0.0,   0.0,  0.0,   -1.41, -0.01],
+    [ 0.0,  1.0,  0.0,  0.0, -1.0,  0.0,   0.0,  0.0,   -1.26, -0.01],
+    [ 1.0,  0.0,  0.0,  0.0, -1.0,  0.0,   0.0,  0.0,   -0.63,  0.00],
+    [ 1.0,  0.0,  0.0,  0.0,  1.0,  0.0,   0.0,  0.0,   -0.63,  0.00],
+    [ 0.0,  1.0,  2.0, -2.0,  3.0,  0.0,   0.0,  0.0,    0.46,  0.00],
+    [ 0.0,  1.0,  2.0, -2.0,  1.0,  0.0,   0.0,  0.0,    0.45,  0.00],
+    [ 0.0,  0.0,  4.0, -4.0,  4.0,  0.0,   0.0,  0.0,    0.36,  0.00],
+    [ 0.0,  0.0,  1.0, -1.0,  1.0, -8.0,  12.0,  0.0,   -0.24, -0.12],
+    [ 0.0,  0.0,  2.0,  0.0,  0.0,  0.0,   0.0,  0.0,    0.32,  0.00],
+    [ 0.0,  0.0,  2.0,  0.0,  2.0,  0.0,   0.0,  0.0,    0.28,  0.00],
+    [ 1.0,  0.0,  2.0,  0.0,  3.0,  0.0,   0.0,  0.0,    0.27,  0.00],
+    [ 1.0,  0.0,  2.0,  0.0,  1.0,  0.0,   0.0,  0.0,    0.26,  0.00],
+    [ 0.0,  0.0,  2.0, -2.0,  0.0,  0.0,   0.0,  0.0,   -0.21,  0.00],
+    [ 0.0,  1.0, -2.0,  2.0, -3.0,  0.0,   0.0,  0.0,    0.19,  0.00],
+    [ 0.0,  1.0, -2.0,  2.0, -1.0,  0.0,   0.0,  0.0,    0.18,  0.00],
+    [ 0.0,  0.0,  0.0,  0.0,  0.0,  8.0, -13.0, -1.0,   -0.10,  0.05],
+    [ 0.0,  0.0,  0.0,  2.0,  0.0,  0.0,   0.0,  0.0,    0.15,  0.00],
+    [ 2.0,  0.0, -2.0,  0.0, -1.0,  0.0,   0.0,  0.0,   -0.14,  0.00],
+    [ 1.0,  0.0,  0.0, -2.0,  1.0,  0.0,   0.0,  0.0,    0.14,  0.00],
+    [ 0.0,  1.0,  2.0, -2.0,  2.0,  0.0,   0.0,  0.0,   -0.14,  0.00],
+    [ 1.0,  0.0,  0.0, -2.0, -1.0,  0.0,   0.0,  0.0,    0.14,  0.00],
+    [ 0.0,  0.0,  4.0, -2.0,  4.0,  0.0,   0.0,  0.0,    0.13,  0.00],
+    [ 0.0,  0.0,  2.0, -2.0,  4.0,  0.0,   0.0,  0.0,   -0.11,  0.00],
+    [ 1.0,  0.0, -2.0,  0.0, -3.0,  0.0,   0.0,  0.0,    0.11,  0.00],
+    [ 1.0,  0.0, -2.0,  0.0, -1.0,  0.0,   0.0,  0.0,    0.11,  0.00],
+];
+
+/**
+Equation of the equinoxes: the right ascension of the mean equinox from the true equinox.
+
+Nutation in longitude projected on the equator plus the complementary terms, IAU 2000 model with the IAU 2000B nutation (see SOFA `iauEe00b`).
+ */
+pub fn equation_of_equinoxes(tt: &JulianDate) -> Angle {
+    let t = tt.julian_centuries();
+    let (longitude, _) = nutation(tt);
+    // NOTE: the IAU 1980 mean obliquity with the IAU 2000 precession-rate correction (see SOFA `iauPr00`).
+    let epsilon = mean_obliquity(tt, ObliquityModel::Iau1980) + Angle::from_arcsec(-0.02524 * t);
+
+    // Fundamental arguments (IERS Conventions 2003): the luni-solar ones in arc seconds, then the mean longitudes of Venus
+    // and the Earth and the general accumulated precession in longitude in radians.
+    let luni_solar = [
+        485868.249036 + t * (1717915923.2178 + t * (31.8792 + t * (0.051635 + t * -0.00024470))),
+        1287104.793048 + t * (129596581.0481 + t * (-0.5532 + t * (0.000136 + t * -0.00001149))),
+        335779.526232 + t * (1739527262.8478 + t * (-12.7512 + t * (-0.001037 + t * 0.00000417))),
+        1072260.703692 + t * (1602961601.2090 + t * (-6.3706 + t * (0.006593 + t * -0.00003169))),
+        450160.398036 + t * (-6962890.5431 + t * (7.4722 + t * (0.007702 + t * -0.00005939))),
+    ]
+    .map(|arcsec| Angle::from_arcsec(arcsec % 1296000.0).to_rad());
+    let planetary = [
+        (3.176146697 + 1021.3285546211 * t) % TWO_PI,
+        (1.753470314 + 628.3075849991 * t) % TWO_PI,
+        (0.024381750 + 0.00000538691 * t) * t,
+    ];
+    let arguments: Vec<f64> = luni_solar.into_iter().chain(planetary).collect();
+
+    // NOTE: sum the smallest terms first to keep precision.
+    let complementary: f64 = COMPLEMENTARY_TERMS
+        .iter()
+        .rev()
+        .map(|term| {
+            let argument: f64 = arguments
+                .iter()
+                .zip(term)
+                .map(|(argument, multiple)| argument * multiple)
+                .sum();
+            term[8] * argument.sin() + term[9] * argument.cos()
+        })
+        .sum();
+    // The only term per Julian century
+    let complementary = complementary - 0.87 * t * arguments[4].sin();
+
+    longitude * epsilon.cos() + Angle::from_arcsec(complementary * 1e-6)
+}
+
+impl GAST {
+    /**
+    GAST of the IAU 2000B model from UT1 and TT julian dates: IAU 2000 GMST plus the [`equation_of_equinoxes`].
+
+    Within 1 mas of the IAU 2000A model between 1995 and 2050 (see SOFA `iauGst00b`).
+     */
+    pub fn iau2000b(ut1: &JulianDate, tt: &JulianDate) -> Self {
+        Self((GMST::iau2000(ut1, tt).0 + equation_of_equinoxes(tt)).normalize_0_24h())
+    }
+
+    /**
+    GAST from UT1 and TT julian dates: IAU 2006 GMST plus the [`equation_of_equinoxes`] with the IAU 2000B nutation.
+
+    Not the full IAU 2006/2000A model, within about 1 mas of SOFA `iauGst06a`.
+     */
+    pub fn iau2006_2000b(ut1: &JulianDate, tt: &JulianDate) -> Self {
+        Self((GMST::iau2006(ut1, tt).0 + equation_of_equinoxes(tt)).normalize_0_24h())
+    }
+
+    /// Local Apparent Sidereal Time at a longitude (east longitudes are positive).
+    pub fn to_local(&self, longitude: &Longitude) -> LAST {
+        LAST(self.at_longitude(longitude))
+    }
+}
+
+/**
+GAST of the IAU 2006 model with the IAU 2000B nutation from a UT1 julian date.
+
+> NOTE: TT is taken equal to UT1, which also evaluates the nutation ΔT early.
+> That changes GAST by up to about 0.1 mas, below the accuracy of the IAU 2000B nutation. Use [`GAST::iau2006_2000b`] with a TT date otherwise.
+ */
+impl From<JulianDate> for GAST {
+    fn from(ut1: JulianDate) -> Self {
+        Self::iau2006_2000b(&ut1, &ut1)
+    }
+}
+
+macro_rules! impl_sidereal_display {
+    ($($T:ty),*) => {
+        $(
+            /// Formats as hours, minutes and seconds like [`HourMinSec`] (e.g. `06h 39m 51.32s`).
+            impl fmt::Display for $T {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Display::fmt(&HourMinSec::from(self.0), f)
+                }
+            }
+        )*
+    };
+}
+
+impl_sidereal_display!(GMST, GAST, LMST, LAST);
+
+impl SiderealTime for GMST {
+    fn at_longitude(&self, longitude: &Longitude) -> Angle {
+        (self.0 + longitude.value()).normalize_0_24h()
+    }
+}
+
+impl SiderealTime for GAST {
+    fn at_longitude(&self, longitude: &Longitude) -> Angle {
+        (self.0 + longitude.value()).normalize_0_24h()
+    }
+}
+
+/// Already local: the longitude is ignored.
+impl SiderealTime for LMST {
+    fn at_longitude(&self, _longitude: &Longitude) -> Angle {
+        self.0
+    }
+}
+
+/// Already local: the longitude is ignored.
+impl SiderealTime for LAST {
+    fn at_longitude(&self, _longitude: &Longitude) -> Angle {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::coord::{Declination, Equitorial, Geographic, Horizontal, Latitude, RightAscension};
+    use crate::time::sidereal::*;
+
+    // Reference values from the SOFA test suite (t_sofa_c.c)
+    #[test]
+    #[allow(clippy::excessive_precision)] // NOTE: reference values have that much precision
+    fn sidereal_time() {
+        let ut1 = JulianDate::new(2400000.5, 53736.0);
+        assert_float_absolute_eq!(GMST::iau1982(&ut1).0.to_rad(), 1.754174981860675096, 1e-12);
+        assert_float_absolute_eq!(
+            GMST::iau2006(&ut1, &ut1).0.to_rad(),
+            1.754174971870091203,
+            1e-12
+        );
+        assert_float_absolute_eq!(GMST::from(ut1).0.to_rad(), 1.754174971870091203, 1e-12);
+        assert_float_absolute_eq!(
+            GMST::iau2000(&ut1, &ut1).0.to_rad(),
+            1.754174972210740592,
+            1e-12
+        );
+        assert_float_absolute_eq!(
+            equation_of_equinoxes(&ut1).to_rad(),
+            -0.8835700060003032831e-5,
+            1e-15
+        );
+        assert_float_absolute_eq!(
+            GAST::iau2000b(&ut1, &ut1).0.to_rad(),
+            1.754166136510680589,
+            1e-12
+        );
+        // NOTE: the IAU 2000B nutation is within 1 mas of the IAU 2006/2000A model.
+        assert_float_absolute_eq!(
+            GAST::iau2006_2000b(&ut1, &ut1).0.to_rad(),
+            1.754166137675019159,
+            Angle::from_mas(1.0).to_rad()
+        );
+
+        assert_float_absolute_eq!(
+            earth_rotation_angle(JulianDate::new(2400000.5, 54388.0))
+                .normalize_0_360()
+                .to_rad(),
+            0.4022837240028158102,
+            1e-12
+        );
+    }
+
+    #[test]
+    fn local_sidereal_time() {
+        let gmst = GMST(Angle::Hour(23.0));
+        let east = Longitude::new(&Angle::Degree(30.0));
+        assert_float_absolute_eq!(gmst.to_local(&east).0.to_hr(), 1.0, 1e-12);
+        assert_float_absolute_eq!(gmst.to_local(&east).at_longitude(&east).to_hr(), 1.0, 1e-12);
+
+        assert_eq!(gmst.to_string(), "23h 00m 00.00s");
+        assert_eq!(format!("{:#.1}", gmst.to_local(&east)), "01:00:00.0");
+        assert!(gmst.to_local(&east) < LMST(Angle::Hour(1.5)));
+        assert_eq!(gmst, GMST(Angle::Hour(23.0)));
+
+        let west = Longitude::new(&Angle::Degree(-75.0));
+        let gast = GAST(Angle::Hour(2.0));
+        assert_float_absolute_eq!(gast.to_local(&west).0.to_hr(), 21.0, 1e-12);
+
+        // Horizontal coordinates are the same from Greenwich or local sidereal time
+        let geo = Geographic {
+            latitude: Latitude::new(&Angle::Degree(40.0)),
+            longitude: east,
+        };
+        let eq = Equitorial {
+            right_ascension: RightAscension::new(&Angle::Hour(3.0)),
+            declination: Declination::new(&Angle::Degree(10.0)),
+        };
+        let greenwich = Horizontal::from_equitorial(&eq, &geo, &gmst);
+        let local = Horizontal::from_equitorial(&eq, &geo, &gmst.to_local(&east));
+        assert_float_absolute_eq!(
+            greenwich.azimuth.value().to_deg(),
+            local.azimuth.value().to_deg(),
+            1e-9
+        );
+    }
+}